@@ -0,0 +1,122 @@
+//! Compares the generated `match_str` to checking every variant with `equals_str` in order.
+//!
+//! Run with `cargo bench`
+use enum_helpers_macros::CompareToStr;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use strum::{EnumIter, IntoEnumIterator};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, CompareToStr, EnumIter)]
+#[compare_str(ignore_ascii_case, allow_overlap)]
+pub enum Large {
+    #[compare_str(equals["application/alpha", "x-alpha", "ALPHA"], contains["alpha-ext"])]
+    Alpha,
+    #[compare_str(equals["application/bravo", "x-bravo", "BRAVO"], contains["bravo-ext"])]
+    Bravo,
+    #[compare_str(equals["application/charlie", "x-charlie", "CHARLIE"], contains["charlie-ext"])]
+    Charlie,
+    #[compare_str(equals["application/delta", "x-delta", "DELTA"], contains["delta-ext"])]
+    Delta,
+    #[compare_str(equals["application/echo", "x-echo", "ECHO"], contains["echo-ext"])]
+    Echo,
+    #[compare_str(equals["application/foxtrot", "x-foxtrot", "FOXTROT"], contains["foxtrot-ext"])]
+    Foxtrot,
+    #[compare_str(equals["application/golf", "x-golf", "GOLF"], contains["golf-ext"])]
+    Golf,
+    #[compare_str(equals["application/hotel", "x-hotel", "HOTEL"], contains["hotel-ext"])]
+    Hotel,
+    #[compare_str(equals["application/india", "x-india", "INDIA"], contains["india-ext"])]
+    India,
+    #[compare_str(equals["application/juliet", "x-juliet", "JULIET"], contains["juliet-ext"])]
+    Juliet,
+    #[compare_str(equals["application/kilo", "x-kilo", "KILO"], contains["kilo-ext"])]
+    Kilo,
+    #[compare_str(equals["application/lima", "x-lima", "LIMA"], contains["lima-ext"])]
+    Lima,
+    #[compare_str(equals["application/mike", "x-mike", "MIKE"], contains["mike-ext"])]
+    Mike,
+    #[compare_str(equals["application/november", "x-november", "NOVEMBER"], contains["november-ext"])]
+    November,
+    #[compare_str(equals["application/oscar", "x-oscar", "OSCAR"], contains["oscar-ext"])]
+    Oscar,
+    #[compare_str(equals["application/papa", "x-papa", "PAPA"], contains["papa-ext"])]
+    Papa,
+    #[compare_str(equals["application/quebec", "x-quebec", "QUEBEC"], contains["quebec-ext"])]
+    Quebec,
+    #[compare_str(equals["application/romeo", "x-romeo", "ROMEO"], contains["romeo-ext"])]
+    Romeo,
+    #[compare_str(equals["application/sierra", "x-sierra", "SIERRA"], contains["sierra-ext"])]
+    Sierra,
+    #[compare_str(equals["application/tango", "x-tango", "TANGO"], contains["tango-ext"])]
+    Tango,
+    #[compare_str(equals["application/uniform", "x-uniform", "UNIFORM"], contains["uniform-ext"])]
+    Uniform,
+    #[compare_str(equals["application/victor", "x-victor", "VICTOR"], contains["victor-ext"])]
+    Victor,
+    #[compare_str(equals["application/whiskey", "x-whiskey", "WHISKEY"], contains["whiskey-ext"])]
+    Whiskey,
+    #[compare_str(equals["application/xray", "x-xray", "XRAY"], contains["xray-ext"])]
+    Xray,
+    #[compare_str(equals["application/yankee", "x-yankee", "YANKEE"], contains["yankee-ext"])]
+    Yankee,
+    #[compare_str(equals["application/zulu", "x-zulu", "ZULU"], contains["zulu-ext"])]
+    Zulu,
+    #[compare_str(equals["application/amber", "x-amber", "AMBER"], contains["amber-ext"])]
+    Amber,
+    #[compare_str(equals["application/basil", "x-basil", "BASIL"], contains["basil-ext"])]
+    Basil,
+    #[compare_str(equals["application/cedar", "x-cedar", "CEDAR"], contains["cedar-ext"])]
+    Cedar,
+    #[compare_str(equals["application/dune", "x-dune", "DUNE"], contains["dune-ext"])]
+    Dune,
+    #[compare_str(equals["application/ember", "x-ember", "EMBER"], contains["ember-ext"])]
+    Ember,
+    #[compare_str(equals["application/fern", "x-fern", "FERN"], contains["fern-ext"])]
+    Fern,
+    #[compare_str(equals["application/grove", "x-grove", "GROVE"], contains["grove-ext"])]
+    Grove,
+    #[compare_str(equals["application/heath", "x-heath", "HEATH"], contains["heath-ext"])]
+    Heath,
+    #[compare_str(equals["application/iris", "x-iris", "IRIS"], contains["iris-ext"])]
+    Iris,
+    #[compare_str(equals["application/jade", "x-jade", "JADE"], contains["jade-ext"])]
+    Jade,
+    #[compare_str(equals["application/kelp", "x-kelp", "KELP"], contains["kelp-ext"])]
+    Kelp,
+    #[compare_str(equals["application/lotus", "x-lotus", "LOTUS"], contains["lotus-ext"])]
+    Lotus,
+    #[compare_str(equals["application/moss", "x-moss", "MOSS"], contains["moss-ext"])]
+    Moss,
+    #[compare_str(equals["application/nectar", "x-nectar", "NECTAR"], contains["nectar-ext"])]
+    Nectar,
+}
+
+fn naive_match(value: &str) -> Option<Large> {
+    Large::iter().find(|variant| variant.equals_str(value))
+}
+
+fn bench(name: &str, inputs: &[&str], f: impl Fn(&str) -> Option<Large>) -> Duration {
+    const ITERATIONS: u32 = 20_000;
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        for input in inputs {
+            black_box(f(black_box(input)));
+        }
+    }
+    let elapsed = start.elapsed();
+    let per_lookup = elapsed / (ITERATIONS * inputs.len() as u32);
+    println!("{name:<32} {per_lookup:?} per lookup");
+    elapsed
+}
+
+fn main() {
+    let exact = ["application/alpha", "X-MOSS", "nectar", "Zulu", "application/unknown"];
+    let contains = ["vendor/lotus-ext+json", "prefix.kelp-ext", "no-match-at-all/here"];
+    for (group, inputs) in [("equals", &exact[..]), ("contains", &contains[..])] {
+        for input in inputs {
+            assert_eq!(Large::match_str(input), naive_match(input));
+        }
+        bench(&format!("{group} naive chain"), inputs, naive_match);
+        bench(&format!("{group} match_str"), inputs, Large::match_str);
+    }
+}
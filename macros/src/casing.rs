@@ -0,0 +1,130 @@
+use syn::{Error, LitStr, Result};
+
+/// A casing rule applied to an identifier.
+///
+/// Used by `CompareToStr` `rename_all` and the `markers` module name of `EnumOfKeys`.
+/// `EnumOfKeys` forwards `rename_all` to the KeyEnum so both derives match the same names
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Casing {
+    /// `lowercase`
+    Lower,
+    /// `UPPERCASE`
+    Upper,
+    /// `PascalCase`
+    Pascal,
+    /// `camelCase`
+    Camel,
+    /// `snake_case`
+    Snake,
+    /// `SCREAMING_SNAKE_CASE`
+    ScreamingSnake,
+    /// `kebab-case`
+    Kebab,
+    /// `SCREAMING-KEBAB-CASE`
+    ScreamingKebab,
+}
+impl Casing {
+    const ALL: [(&'static str, Casing); 8] = [
+        ("lowercase", Casing::Lower),
+        ("UPPERCASE", Casing::Upper),
+        ("PascalCase", Casing::Pascal),
+        ("camelCase", Casing::Camel),
+        ("snake_case", Casing::Snake),
+        ("SCREAMING_SNAKE_CASE", Casing::ScreamingSnake),
+        ("kebab-case", Casing::Kebab),
+        ("SCREAMING-KEBAB-CASE", Casing::ScreamingKebab),
+    ];
+    /// Parses the name of a casing. Such as `"kebab-case"`
+    pub fn from_lit(value: &LitStr) -> Result<Self> {
+        let name = value.value();
+        Self::ALL
+            .iter()
+            .find(|(casing_name, _)| *casing_name == name)
+            .map(|(_, casing)| *casing)
+            .ok_or_else(|| {
+                let names = Self::ALL
+                    .iter()
+                    .map(|(name, _)| format!("`{name}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                Error::new(
+                    value.span(),
+                    format!("Unknown casing `{name}`. Expected one of {names}"),
+                )
+            })
+    }
+    /// Converts an identifier to this casing
+    pub fn apply(self, value: &str) -> String {
+        let words = split_words(value);
+        match self {
+            Casing::Lower => words.concat().to_lowercase(),
+            Casing::Upper => words.concat().to_uppercase(),
+            Casing::Pascal => words.iter().map(|word| capitalize(word)).collect(),
+            Casing::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(index, word)| {
+                    if index == 0 {
+                        word.to_lowercase()
+                    } else {
+                        capitalize(word)
+                    }
+                })
+                .collect(),
+            Casing::Snake => join(&words, '_', false),
+            Casing::ScreamingSnake => join(&words, '_', true),
+            Casing::Kebab => join(&words, '-', false),
+            Casing::ScreamingKebab => join(&words, '-', true),
+        }
+    }
+}
+fn join(words: &[&str], separator: char, upper: bool) -> String {
+    let mut result = String::with_capacity(words.iter().map(|word| word.len() + 1).sum());
+    for (index, word) in words.iter().enumerate() {
+        if index != 0 {
+            result.push(separator);
+        }
+        if upper {
+            result.push_str(&word.to_uppercase());
+        } else {
+            result.push_str(&word.to_lowercase());
+        }
+    }
+    result
+}
+/// Uppercases the first character. The rest of the word is kept as is
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+/// Splits an identifier into words.
+///
+/// Words are separated by `_`, `-` and case changes. Acronyms stay together. `StartTLS` is `Start` `TLS` and `HTTPServer` is `HTTP` `Server`
+fn split_words(value: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    for part in value.split(['_', '-']) {
+        let chars: Vec<(usize, char)> = part.char_indices().collect();
+        let mut start = 0;
+        for (index, (offset, c)) in chars.iter().enumerate() {
+            if index == 0 || !c.is_uppercase() {
+                continue;
+            }
+            let previous = chars[index - 1].1;
+            let next = chars.get(index + 1).map(|(_, c)| *c);
+            let starts_word = previous.is_lowercase()
+                || previous.is_numeric()
+                || (previous.is_uppercase() && next.is_some_and(|n| n.is_lowercase()));
+            if starts_word {
+                words.push(&part[start..*offset]);
+                start = *offset;
+            }
+        }
+        if start < part.len() {
+            words.push(&part[start..]);
+        }
+    }
+    words
+}
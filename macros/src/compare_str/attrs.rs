@@ -0,0 +1,358 @@
+use crate::casing::Casing;
+use crate::compare_str::case_mode::CaseMode;
+use crate::compare_str::pattern::{Pattern, PatternKind};
+use crate::utils::consume_comma;
+use syn::parse::Parse;
+use syn::{bracketed, Error, LitStr, Member, Path, Result, Token, Type};
+
+mod keywords {
+    use syn::custom_keyword;
+    custom_keyword!(equals);
+    custom_keyword!(contains);
+    custom_keyword!(starts_with);
+    custom_keyword!(ends_with);
+    custom_keyword!(word);
+    custom_keyword!(path_suffix);
+    custom_keyword!(glob);
+    custom_keyword!(include_variant);
+    custom_keyword!(rename_all);
+    custom_keyword!(deref_str);
+    custom_keyword!(partial_eq);
+    custom_keyword!(bytes);
+    custom_keyword!(to_lowercase);
+    custom_keyword!(ignore_ascii_case);
+    custom_keyword!(case_fold);
+    custom_keyword!(from_str);
+    custom_keyword!(key);
+    custom_keyword!(allow_overlap);
+    custom_keyword!(field);
+    custom_keyword!(except);
+    custom_keyword!(fallback);
+    custom_keyword!(with);
+    custom_keyword!(with_self);
+    custom_keyword!(similar);
+    custom_keyword!(equals_from);
+    custom_keyword!(threshold);
+}
+#[derive(Debug)]
+pub struct TypeAttribute {
+    pub include_variant: bool,
+    /// The casings of the variant name matched by `include_variant`.
+    /// Empty keeps the name as written
+    /// #[compare_str(rename_all = "kebab-case")] or #[compare_str(rename_all["kebab-case", "snake_case"])]
+    pub rename_all: Vec<Casing>,
+    pub partial_eq: bool,
+    /// Generate `equals_bytes` and `equals_os_str`. Along with `PartialEq<[u8]>` if `partial_eq` is set
+    pub bytes: bool,
+    /// How the case of the string is handled.
+    ///
+    /// Set with `ignore_ascii_case`, `case_fold` or `to_lowercase`
+    pub case_mode: CaseMode,
+    /// Implement FromStr using `match_str`
+    pub from_str: bool,
+    /// The KeyEnum returned by `match_key`
+    /// #[compare_str(key = OtherTypesKeys)]
+    pub key: Option<Type>,
+    /// Do not warn about patterns of different variants matching the same string
+    pub allow_overlap: bool,
+}
+impl Default for TypeAttribute {
+    fn default() -> Self {
+        Self {
+            include_variant: true,
+            rename_all: Vec::new(),
+            partial_eq: true,
+            bytes: false,
+            case_mode: CaseMode::Sensitive,
+            from_str: false,
+            key: None,
+            allow_overlap: false,
+        }
+    }
+}
+/// Parses an optional `= bool`. Defaults to true
+fn parse_bool_value(input: syn::parse::ParseStream) -> Result<bool> {
+    if input.parse::<Token![=]>().is_ok() {
+        Ok(input.parse::<syn::LitBool>()?.value())
+    } else {
+        Ok(true)
+    }
+}
+impl Parse for TypeAttribute {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        let mut type_attribute = Self::default();
+        while !input.is_empty() {
+            let peek = input.lookahead1();
+            if peek.peek(keywords::include_variant) {
+                input.parse::<keywords::include_variant>()?;
+                type_attribute.include_variant = parse_bool_value(input)?;
+            } else if peek.peek(keywords::rename_all) {
+                input.parse::<keywords::rename_all>()?;
+                let names = if input.parse::<Option<Token![=]>>()?.is_some() {
+                    vec![input.parse::<LitStr>()?]
+                } else {
+                    let content;
+                    bracketed!(content in input);
+                    content
+                        .parse_terminated(|input| input.parse::<LitStr>(), Token![,])?
+                        .into_iter()
+                        .collect()
+                };
+                for name in names {
+                    let casing = Casing::from_lit(&name)?;
+                    if !type_attribute.rename_all.contains(&casing) {
+                        type_attribute.rename_all.push(casing);
+                    }
+                }
+            } else if peek.peek(keywords::to_lowercase) {
+                input.parse::<keywords::to_lowercase>()?;
+                // to_lowercase is kept for compatibility. It behaves like case_fold
+                if parse_bool_value(input)? {
+                    type_attribute.case_mode = CaseMode::CaseFold;
+                }
+            } else if peek.peek(keywords::case_fold) {
+                input.parse::<keywords::case_fold>()?;
+                if parse_bool_value(input)? {
+                    type_attribute.case_mode = CaseMode::CaseFold;
+                }
+            } else if peek.peek(keywords::ignore_ascii_case) {
+                input.parse::<keywords::ignore_ascii_case>()?;
+                if parse_bool_value(input)? {
+                    type_attribute.case_mode = CaseMode::AsciiCaseInsensitive;
+                }
+            } else if peek.peek(keywords::from_str) {
+                input.parse::<keywords::from_str>()?;
+                type_attribute.from_str = parse_bool_value(input)?;
+            } else if peek.peek(keywords::key) {
+                input.parse::<keywords::key>()?;
+                input.parse::<Token![=]>()?;
+                type_attribute.key = Some(input.parse()?);
+            } else if peek.peek(keywords::allow_overlap) {
+                input.parse::<keywords::allow_overlap>()?;
+                type_attribute.allow_overlap = parse_bool_value(input)?;
+            } else if peek.peek(keywords::bytes) {
+                input.parse::<keywords::bytes>()?;
+                type_attribute.bytes = parse_bool_value(input)?;
+            } else if peek.peek(keywords::partial_eq) {
+                input.parse::<keywords::partial_eq>()?;
+                type_attribute.partial_eq = parse_bool_value(input)?;
+            } else {
+                return Err(peek.error());
+            }
+            let _ = input.parse::<Token![,]>();
+        }
+        Ok(type_attribute)
+    }
+}
+/// This attribute is used to generate an compare to str
+///
+/// A variant matches if any of its patterns match.
+///
+/// ```ignore
+/// #[compare_str(equals["uuid", "uuid::Uuid"], contains["uuid"], starts_with["uuid"], ends_with["Uuid"], word["uuid"], path_suffix["uuid::Uuid"], glob["uuid*"])]
+/// ```
+#[derive(Debug, Default)]
+pub struct CompareToStrAttribute {
+    pub patterns: Vec<Pattern>,
+    /// Do not warn about other variants matching the patterns of this variant
+    pub allow_overlap: bool,
+    /// Compare against a string field of the variant.
+    /// `field` for the only field. `field = name` or `field = 0` to pick one
+    pub field: Option<Option<Member>>,
+    /// Strings the variant never matches. Even if a pattern matches them
+    /// `except["uuid_legacy"]`
+    pub except: Vec<Pattern>,
+    /// The variant matches any string not matched by the patterns of another variant
+    pub fallback: bool,
+    /// Functions `fn(&str) -> bool` called with the string normalized for the case mode
+    /// `with = path::to::fn`
+    pub with: Vec<Path>,
+    /// Functions `fn(&Self, &str) -> bool`. Like `field` they are only used when comparing a value
+    /// `with_self = path::to::fn`
+    pub with_self: Vec<Path>,
+    /// Also match strings at most this many edits away from an `equals` pattern
+    /// `similar` or `similar[threshold = 2]`. Defaults to 1
+    pub similar: Option<usize>,
+    /// Absolute paths of the `equals_from` files. Included in the output so Cargo rebuilds when they change
+    pub tracked_files: Vec<String>,
+}
+impl CompareToStrAttribute {
+    pub fn extend(&mut self, other: CompareToStrAttribute) {
+        self.patterns.extend(other.patterns);
+        self.except.extend(other.except);
+        self.allow_overlap |= other.allow_overlap;
+        self.fallback |= other.fallback;
+        self.with.extend(other.with);
+        self.with_self.extend(other.with_self);
+        self.tracked_files.extend(other.tracked_files);
+        if other.similar.is_some() {
+            self.similar = other.similar;
+        }
+        if other.field.is_some() {
+            self.field = other.field;
+        }
+    }
+    /// Checks if a normalized string is in the `except` list
+    pub fn excepts(&self, value: &str) -> bool {
+        self.except
+            .iter()
+            .any(|except| except.value.value() == value)
+    }
+    /// Normalizes every literal for the case mode
+    ///
+    /// # Errors
+    /// If a string is both matched by `equals` and in the `except` list
+    pub fn normalize(&mut self, case_mode: CaseMode) -> Result<()> {
+        for pattern in self.patterns.iter_mut().chain(self.except.iter_mut()) {
+            pattern.normalize(case_mode)?;
+        }
+        for except in &self.except {
+            if let Some(pattern) = self.patterns.iter().find(|pattern| {
+                pattern.kind == PatternKind::Equals && pattern.value.value() == except.value.value()
+            }) {
+                return Err(Error::new(
+                    except.value.span(),
+                    format!(
+                        "`{}` is also declared by `{}`",
+                        except.source.value(),
+                        pattern.display()
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+/// Reads `equals` patterns from a file relative to `CARGO_MANIFEST_DIR`.
+///
+/// One pattern per line. Empty lines and lines starting with `#` are skipped.
+/// Returns the absolute path of the file
+fn read_patterns_file(file: &LitStr, patterns: &mut Vec<Pattern>) -> Result<String> {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+        .map_err(|_| Error::new(file.span(), "CARGO_MANIFEST_DIR is not set"))?;
+    let path = std::path::Path::new(&manifest_dir).join(file.value());
+    let content = std::fs::read_to_string(&path).map_err(|err| {
+        Error::new(
+            file.span(),
+            format!("Unable to read `{}`: {err}", path.display()),
+        )
+    })?;
+    for line in content.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        patterns.push(Pattern::new(
+            PatternKind::Equals,
+            LitStr::new(line, file.span()),
+        )?);
+    }
+    path.to_str()
+        .map(str::to_owned)
+        .ok_or_else(|| Error::new(file.span(), "The path is not valid UTF-8"))
+}
+/// Parses `[ "a", "b" ]` into patterns of the kind
+fn parse_pattern_list(
+    input: syn::parse::ParseStream,
+    kind: PatternKind,
+    patterns: &mut Vec<Pattern>,
+) -> Result<()> {
+    let content;
+    bracketed!(content in input);
+    let list = content.parse_terminated(<LitStr as Parse>::parse, Token![,])?;
+    for value in list {
+        patterns.push(Pattern::new(kind, value)?);
+    }
+    Ok(())
+}
+impl Parse for CompareToStrAttribute {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        let mut patterns = Vec::new();
+        let mut allow_overlap = false;
+        let mut field = None;
+        let mut except = Vec::new();
+        let mut fallback = false;
+        let mut with = Vec::new();
+        let mut with_self = Vec::new();
+        let mut similar = None;
+        let mut tracked_files = Vec::new();
+        while !input.is_empty() {
+            let peek = input.lookahead1();
+            if peek.peek(keywords::equals) {
+                input.parse::<keywords::equals>()?;
+                parse_pattern_list(input, PatternKind::Equals, &mut patterns)?;
+            } else if peek.peek(keywords::equals_from) {
+                input.parse::<keywords::equals_from>()?;
+                input.parse::<Token![=]>()?;
+                let file = input.parse::<LitStr>()?;
+                tracked_files.push(read_patterns_file(&file, &mut patterns)?);
+            } else if peek.peek(keywords::contains) {
+                input.parse::<keywords::contains>()?;
+                parse_pattern_list(input, PatternKind::Contains, &mut patterns)?;
+            } else if peek.peek(keywords::starts_with) {
+                input.parse::<keywords::starts_with>()?;
+                parse_pattern_list(input, PatternKind::StartsWith, &mut patterns)?;
+            } else if peek.peek(keywords::ends_with) {
+                input.parse::<keywords::ends_with>()?;
+                parse_pattern_list(input, PatternKind::EndsWith, &mut patterns)?;
+            } else if peek.peek(keywords::word) {
+                input.parse::<keywords::word>()?;
+                parse_pattern_list(input, PatternKind::Word, &mut patterns)?;
+            } else if peek.peek(keywords::path_suffix) {
+                input.parse::<keywords::path_suffix>()?;
+                parse_pattern_list(input, PatternKind::PathSuffix, &mut patterns)?;
+            } else if peek.peek(keywords::glob) {
+                input.parse::<keywords::glob>()?;
+                parse_pattern_list(input, PatternKind::Glob, &mut patterns)?;
+            } else if peek.peek(keywords::except) {
+                input.parse::<keywords::except>()?;
+                parse_pattern_list(input, PatternKind::Equals, &mut except)?;
+            } else if peek.peek(keywords::with) {
+                input.parse::<keywords::with>()?;
+                input.parse::<Token![=]>()?;
+                with.push(input.parse()?);
+            } else if peek.peek(keywords::with_self) {
+                input.parse::<keywords::with_self>()?;
+                input.parse::<Token![=]>()?;
+                with_self.push(input.parse()?);
+            } else if peek.peek(keywords::similar) {
+                input.parse::<keywords::similar>()?;
+                if input.peek(syn::token::Bracket) {
+                    let content;
+                    bracketed!(content in input);
+                    content.parse::<keywords::threshold>()?;
+                    content.parse::<Token![=]>()?;
+                    similar = Some(content.parse::<syn::LitInt>()?.base10_parse()?);
+                } else {
+                    similar = Some(1);
+                }
+            } else if peek.peek(keywords::fallback) {
+                input.parse::<keywords::fallback>()?;
+                fallback = true;
+            } else if peek.peek(keywords::allow_overlap) {
+                input.parse::<keywords::allow_overlap>()?;
+                allow_overlap = true;
+            } else if peek.peek(keywords::field) {
+                input.parse::<keywords::field>()?;
+                if input.parse::<Option<Token![=]>>()?.is_some() {
+                    field = Some(Some(input.parse()?));
+                } else {
+                    field = Some(None);
+                }
+            } else {
+                return Err(peek.error());
+            }
+            consume_comma!(input);
+        }
+        Ok(Self {
+            patterns,
+            allow_overlap,
+            field,
+            except,
+            fallback,
+            with,
+            with_self,
+            similar,
+            tracked_files,
+        })
+    }
+}
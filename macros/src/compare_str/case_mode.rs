@@ -0,0 +1,47 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::LitStr;
+
+/// How CompareToStr handles the case of the compared string.
+///
+/// Mirrors `enum_helper::compare_str::CaseMode`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CaseMode {
+    /// Exact comparison
+    #[default]
+    Sensitive,
+    /// `ignore_ascii_case`. Uses `eq_ignore_ascii_case`
+    AsciiCaseInsensitive,
+    /// `case_fold`. Compares the Unicode full case folding of both strings
+    CaseFold,
+}
+impl CaseMode {
+    /// Normalizes a pattern literal at macro time so it can be compared against the normalized input
+    pub fn normalize(&self, lit: &LitStr) -> LitStr {
+        let value = lit.value();
+        let normalized = match self {
+            CaseMode::Sensitive => return lit.clone(),
+            CaseMode::AsciiCaseInsensitive => value.to_ascii_lowercase(),
+            CaseMode::CaseFold => value.chars().flat_map(fold_char).collect(),
+        };
+        LitStr::new(&normalized, lit.span())
+    }
+    /// The matching `enum_helper::compare_str::CaseMode`
+    pub fn runtime(&self) -> TokenStream {
+        match self {
+            CaseMode::Sensitive => quote! { enum_helper::compare_str::CaseMode::Sensitive },
+            CaseMode::AsciiCaseInsensitive => {
+                quote! { enum_helper::compare_str::CaseMode::AsciiCaseInsensitive }
+            }
+            CaseMode::CaseFold => quote! { enum_helper::compare_str::CaseMode::CaseFold },
+        }
+    }
+}
+/// Mirrors `enum_helper::compare_str::fold_char`. Both must fold a character the same way
+fn fold_char(c: char) -> impl Iterator<Item = char> {
+    let dotless_i = c == 'ı';
+    c.to_lowercase()
+        .flat_map(char::to_uppercase)
+        .flat_map(char::to_lowercase)
+        .map(move |folded| if dotless_i { 'ı' } else { folded })
+}
@@ -0,0 +1,103 @@
+use crate::compare_str::attrs::TypeAttribute;
+use crate::compare_str::case_mode::CaseMode;
+use crate::compare_str::pattern::{Pattern, PatternKind};
+use crate::compare_str::variant::CompareToStrVariant;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::Generics;
+
+/// The const checks of the patterns matching the filter. None if there are none
+fn const_matches(
+    variant: &CompareToStrVariant,
+    case_mode: CaseMode,
+    filter: impl Fn(&Pattern) -> bool,
+) -> Option<TokenStream> {
+    let other = quote!(other);
+    let checks = variant
+        .attributes
+        .patterns
+        .iter()
+        .filter(|pattern| filter(pattern))
+        .filter_map(|pattern| pattern.const_matches_expr(case_mode, &other))
+        .collect::<Vec<_>>();
+    if checks.is_empty() {
+        return None;
+    }
+    let except = variant
+        .attributes
+        .except
+        .iter()
+        .filter_map(|pattern| pattern.const_matches_expr(case_mode, &other))
+        .collect::<Vec<_>>();
+    if except.is_empty() {
+        Some(quote! { #(#checks)||* })
+    } else {
+        Some(quote! { !(#(#except)||*) && (#(#checks)||*) })
+    }
+}
+
+/// Generates `equals_str_const` and `match_str_const`.
+///
+/// Only `equals`, `starts_with` and `ends_with` patterns are checked.
+/// Nothing is generated for `case_fold`. Unicode lowercasing is not available in a const fn
+pub(crate) fn expand_const(
+    ident: &Ident,
+    generics: &Generics,
+    variants: &[CompareToStrVariant],
+    type_attr: &TypeAttribute,
+) -> TokenStream {
+    let case_mode = type_attr.case_mode;
+    if case_mode == CaseMode::CaseFold {
+        return quote! {};
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let equals_lines = variants.iter().map(|variant| {
+        let matches =
+            const_matches(variant, case_mode, |_| true).unwrap_or_else(|| quote! { false });
+        variant.create_static_line(matches)
+    });
+    let unit_variants = variants.iter().filter(|v| v.is_unit).collect::<Vec<_>>();
+    // Exact matches are checked first. Like `match_str`
+    let tiers: [fn(&Pattern) -> bool; 2] = [
+        |pattern: &Pattern| pattern.kind == PatternKind::Equals,
+        |pattern: &Pattern| pattern.kind != PatternKind::Equals,
+    ];
+    let checks = tiers.iter().flat_map(|tier| {
+        unit_variants.iter().filter_map(move |variant| {
+            let matches = const_matches(variant, case_mode, *tier)?;
+            let name = &variant.name;
+            let cfg_attrs = &variant.cfg_attrs;
+            Some(quote! {
+                #(#cfg_attrs)*
+                if #matches {
+                    return ::core::option::Option::Some(Self::#name);
+                }
+            })
+        })
+    });
+    quote! {
+        #[automatically_derived]
+        #[allow(deprecated)]
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Compares an enum variant to a str in a const context.
+            ///
+            /// Only `equals`, `starts_with` and `ends_with` patterns are checked
+            pub const fn equals_str_const(&self, other: &str) -> bool {
+                #[allow(unused_variables)]
+                let other = other.as_bytes();
+                match self {
+                    #(#equals_lines),*
+                }
+            }
+            /// Finds the unit variant matching the str in a const context.
+            ///
+            /// Only `equals`, `starts_with` and `ends_with` patterns are checked
+            pub const fn match_str_const(other: &str) -> ::core::option::Option<Self> {
+                #[allow(unused_variables)]
+                let other = other.as_bytes();
+                #(#checks)*
+                ::core::option::Option::None
+            }
+        }
+    }
+}
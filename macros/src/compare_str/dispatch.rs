@@ -0,0 +1,156 @@
+use crate::compare_str::case_mode::CaseMode;
+use crate::compare_str::pattern::PatternKind;
+use crate::compare_str::variant::CompareToStrVariant;
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+/// Below this number of `contains` patterns a chain of `contains` calls is generated instead of an automaton
+pub const MULTI_PATTERN_THRESHOLD: usize = 4;
+
+/// Generates a `match` on the bytes of `other` for every `equals` pattern.
+///
+/// rustc compiles slice patterns into a dispatch on the length and then on each byte.
+/// Returns None for `case_fold` as it can not be expressed as bytes
+pub fn exact_dispatch(
+    variants: &[&CompareToStrVariant],
+    case_mode: CaseMode,
+    value: &dyn Fn(&CompareToStrVariant) -> TokenStream,
+) -> Option<TokenStream> {
+    if case_mode == CaseMode::CaseFold {
+        return None;
+    }
+    let arms = variants
+        .iter()
+        .filter_map(|variant| {
+            let literals = variant
+                .attributes
+                .patterns
+                .iter()
+                .filter(|pattern| pattern.kind == PatternKind::Equals)
+                .map(|pattern| pattern.value.value())
+                .collect::<BTreeSet<_>>();
+            if literals.is_empty() {
+                return None;
+            }
+            let patterns = literals
+                .iter()
+                .map(|literal| byte_pattern(literal, case_mode));
+            let cfg_attrs = &variant.cfg_attrs;
+            let value = value(variant);
+            Some(quote! {
+                #(#cfg_attrs)*
+                #(#patterns)|* => return ::core::option::Option::Some(#value),
+            })
+        })
+        .collect::<Vec<_>>();
+    if arms.is_empty() {
+        return Some(quote! {});
+    }
+    Some(quote! {
+        match other.as_bytes() {
+            #(#arms)*
+            _ => {}
+        }
+    })
+}
+/// A slice pattern matching the literal. Letters match both cases in `ignore_ascii_case`
+fn byte_pattern(literal: &str, case_mode: CaseMode) -> TokenStream {
+    let bytes = literal.bytes().map(|byte| {
+        let lit = Literal::byte_character(byte);
+        if case_mode == CaseMode::AsciiCaseInsensitive && byte.is_ascii_lowercase() {
+            let upper = Literal::byte_character(byte.to_ascii_uppercase());
+            quote! { #lit | #upper }
+        } else {
+            quote! { #lit }
+        }
+    });
+    quote! { [#(#bytes),*] }
+}
+/// An Aho-Corasick automaton built at macro time.
+///
+/// Finds the lowest index of all patterns contained in a string in a single pass
+pub struct MultiPattern {
+    classes: [u8; 256],
+    num_classes: usize,
+    transitions: Vec<u32>,
+    outputs: Vec<u32>,
+}
+impl MultiPattern {
+    /// Builds the automaton. Each pattern is paired with the index returned when it is found
+    pub fn build(patterns: &[(String, u32)]) -> Self {
+        // Bytes that do not appear in any pattern share class 0
+        let mut classes = [0u8; 256];
+        let mut num_classes = 1usize;
+        let used = patterns
+            .iter()
+            .flat_map(|(pattern, _)| pattern.bytes())
+            .collect::<BTreeSet<_>>();
+        for byte in used {
+            classes[byte as usize] = num_classes as u8;
+            num_classes += 1;
+        }
+        let mut goto: Vec<BTreeMap<u8, usize>> = vec![BTreeMap::new()];
+        let mut outputs = vec![u32::MAX];
+        for (pattern, index) in patterns {
+            let mut state = 0;
+            for class in pattern.bytes().map(|byte| classes[byte as usize]) {
+                state = match goto[state].get(&class) {
+                    Some(next) => *next,
+                    None => {
+                        goto.push(BTreeMap::new());
+                        outputs.push(u32::MAX);
+                        let next = goto.len() - 1;
+                        goto[state].insert(class, next);
+                        next
+                    }
+                };
+            }
+            outputs[state] = outputs[state].min(*index);
+        }
+        let mut transitions = vec![0u32; goto.len() * num_classes];
+        let mut fail = vec![0usize; goto.len()];
+        let mut queue = VecDeque::new();
+        for (class, next) in &goto[0] {
+            transitions[*class as usize] = *next as u32;
+            queue.push_back(*next);
+        }
+        while let Some(state) = queue.pop_front() {
+            outputs[state] = outputs[state].min(outputs[fail[state]]);
+            for class in 0..num_classes {
+                let fallback = transitions[fail[state] * num_classes + class];
+                match goto[state].get(&(class as u8)) {
+                    Some(next) => {
+                        fail[*next] = fallback as usize;
+                        transitions[state * num_classes + class] = *next as u32;
+                        queue.push_back(*next);
+                    }
+                    None => transitions[state * num_classes + class] = fallback,
+                }
+            }
+        }
+        Self {
+            classes,
+            num_classes,
+            transitions,
+            outputs,
+        }
+    }
+    /// An expression building a `enum_helper::compare_str::MultiPattern`
+    pub fn to_tokens(&self) -> TokenStream {
+        let Self {
+            classes,
+            num_classes,
+            transitions,
+            outputs,
+        } = self;
+        quote! {
+            enum_helper::compare_str::MultiPattern {
+                classes: [#(#classes),*],
+                num_classes: #num_classes,
+                transitions: &[#(#transitions),*],
+                outputs: &[#(#outputs),*],
+            }
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use crate::compare_str::case_mode::CaseMode;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// A glob pattern compiled at macro time.
+///
+/// Supports `*` any sequence, `?` any character, `[abc]` `[a-z]` `[!abc]` character classes and `\` to escape.
+/// The pattern is split on `*` into segments. Each segment is generated as a function returning the length of the prefix it matches
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Glob {
+    pub segments: Vec<Vec<GlobToken>>,
+}
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GlobToken {
+    Literal(String),
+    AnyChar,
+    Class {
+        negated: bool,
+        items: Vec<(char, char)>,
+    },
+}
+impl Glob {
+    pub fn parse(pattern: &str) -> Result<Self, String> {
+        let mut segments = vec![Vec::new()];
+        let mut chars = pattern.chars().peekable();
+        while let Some(c) = chars.next() {
+            let segment = segments.last_mut().expect("At least one segment");
+            match c {
+                '*' => segments.push(Vec::new()),
+                '?' => segment.push(GlobToken::AnyChar),
+                '\\' => push_literal(segment, unescape(chars.next())?),
+                '[' => {
+                    let negated = chars.next_if_eq(&'!').is_some();
+                    let mut items = Vec::new();
+                    loop {
+                        let start = match chars.next() {
+                            None => return Err("Unclosed `[` in glob".to_owned()),
+                            Some(']') if !items.is_empty() => break,
+                            Some('\\') => unescape(chars.next())?,
+                            Some(c) => c,
+                        };
+                        if chars.next_if_eq(&'-').is_some() {
+                            let end = match chars.next() {
+                                None | Some(']') => {
+                                    return Err(format!("Unfinished character range `{start}-` in glob"))
+                                }
+                                Some('\\') => unescape(chars.next())?,
+                                Some(c) => c,
+                            };
+                            if end < start {
+                                return Err(format!(
+                                    "Invalid character range `{start}-{end}` in glob"
+                                ));
+                            }
+                            items.push((start, end));
+                        } else {
+                            items.push((start, start));
+                        }
+                    }
+                    segment.push(GlobToken::Class { negated, items });
+                }
+                c => push_literal(segment, c),
+            }
+        }
+        Ok(Self { segments })
+    }
+    /// Matches the glob against a normalized string at macro time
+    pub fn matches(&self, value: &str) -> bool {
+        fn segment_len(segment: &[GlobToken], value: &str) -> Option<usize> {
+            let mut pos = 0;
+            for token in segment {
+                match token {
+                    GlobToken::Literal(literal) => {
+                        if !value[pos..].starts_with(literal.as_str()) {
+                            return None;
+                        }
+                        pos += literal.len();
+                    }
+                    GlobToken::AnyChar => pos += value[pos..].chars().next()?.len_utf8(),
+                    GlobToken::Class { negated, items } => {
+                        let c = value[pos..].chars().next()?;
+                        let in_class = items.iter().any(|(start, end)| (*start..=*end).contains(&c));
+                        if in_class == *negated {
+                            return None;
+                        }
+                        pos += c.len_utf8();
+                    }
+                }
+            }
+            Some(pos)
+        }
+        fn matches_from(segments: &[Vec<GlobToken>], value: &str, anchored: bool) -> bool {
+            let Some((first, rest)) = segments.split_first() else {
+                return value.is_empty();
+            };
+            let starts = value
+                .char_indices()
+                .map(|(index, _)| index)
+                .chain(std::iter::once(value.len()))
+                .take(if anchored { 1 } else { usize::MAX });
+            for start in starts {
+                if let Some(len) = segment_len(first, &value[start..]) {
+                    let end = start + len;
+                    if rest.is_empty() {
+                        if end == value.len() {
+                            return true;
+                        }
+                    } else if matches_from(rest, &value[end..], false) {
+                        return true;
+                    }
+                }
+            }
+            false
+        }
+        matches_from(&self.segments, value, true)
+    }
+    /// An expression checking if `value` matches the glob
+    pub fn matches_expr(&self, case_mode: CaseMode, value: &TokenStream) -> TokenStream {
+        let segments = self
+            .segments
+            .iter()
+            .map(|segment| segment_fn(segment, case_mode));
+        quote! {
+            enum_helper::compare_str::glob_matches(#value, &[#(#segments),*])
+        }
+    }
+}
+fn unescape(c: Option<char>) -> Result<char, String> {
+    c.ok_or_else(|| "The glob ends with an unfinished escape".to_owned())
+}
+fn push_literal(segment: &mut Vec<GlobToken>, c: char) {
+    if let Some(GlobToken::Literal(literal)) = segment.last_mut() {
+        literal.push(c);
+    } else {
+        segment.push(GlobToken::Literal(c.to_string()));
+    }
+}
+/// Generates a function returning the length of the prefix of the string matching the segment
+fn segment_fn(segment: &[GlobToken], case_mode: CaseMode) -> TokenStream {
+    if segment.is_empty() {
+        return quote! {
+            |_: &str| -> ::core::option::Option<usize> { ::core::option::Option::Some(0) }
+        };
+    }
+    let case = case_mode.runtime();
+    let steps = segment.iter().map(|token| match token {
+        GlobToken::Literal(literal) => quote! {
+            pos += #case.prefix_len(&value[pos..], #literal)?;
+        },
+        GlobToken::AnyChar => quote! {
+            pos += value[pos..].chars().next()?.len_utf8();
+        },
+        GlobToken::Class { negated, items } => {
+            let items = items.iter().map(|(start, end)| {
+                if start == end {
+                    quote! { #start }
+                } else {
+                    quote! { #start..=#end }
+                }
+            });
+            let test = quote! { matches!(#case.normalize_char(c), #(#items)|*) };
+            let test = if *negated { quote!(!#test) } else { test };
+            quote! {
+                let c = value[pos..].chars().next()?;
+                if !#test {
+                    return ::core::option::Option::None;
+                }
+                pos += c.len_utf8();
+            }
+        }
+    });
+    quote! {
+        |value: &str| -> ::core::option::Option<usize> {
+            let mut pos = 0usize;
+            #(#steps)*
+            ::core::option::Option::Some(pos)
+        }
+    }
+}
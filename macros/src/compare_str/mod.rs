@@ -1,177 +1,30 @@
-use crate::utils::{consume_comma, into_enum};
-use proc_macro2::{Ident, TokenStream};
-use quote::{quote, ToTokens, TokenStreamExt};
-use syn::parse::Parse;
+mod attrs;
+mod case_mode;
+mod const_fn;
+mod dispatch;
+mod glob;
+mod overlap;
+mod pattern;
+mod reverse;
+mod variant;
 
-use syn::{bracketed, Fields, LitStr, Result, Token, Variant};
-use syn::{DeriveInput, Error};
-
-mod keywords {
-    use syn::custom_keyword;
-    custom_keyword!(equals);
-    custom_keyword!(contains);
-    custom_keyword!(include_variant);
-    custom_keyword!(deref_str);
-    custom_keyword!(partial_eq);
-    custom_keyword!(to_lowercase);
-}
-#[derive(Debug)]
-pub struct TypeAttribute {
-    pub include_variant: bool,
-    pub partial_eq: bool,
-    pub to_lowercase: bool,
-}
-impl Default for TypeAttribute {
-    fn default() -> Self {
-        Self {
-            include_variant: true,
-            partial_eq: true,
-            to_lowercase: false,
-        }
-    }
-}
-impl Parse for TypeAttribute {
-    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
-        let mut type_attribute = Self::default();
-        while !input.is_empty() {
-            let peek = input.lookahead1();
-            if peek.peek(keywords::include_variant) {
-                input.parse::<keywords::include_variant>()?;
-                if input.parse::<Token![=]>().is_ok() {
-                    type_attribute.include_variant = input.parse::<syn::LitBool>()?.value();
-                } else {
-                    type_attribute.include_variant = true;
-                }
-            } else if peek.peek(keywords::to_lowercase) {
-                input.parse::<keywords::to_lowercase>()?;
-                if input.parse::<Token![=]>().is_ok() {
-                    type_attribute.to_lowercase = input.parse::<syn::LitBool>()?.value();
-                } else {
-                    type_attribute.to_lowercase = true;
-                }
-            } else if peek.peek(keywords::partial_eq) {
-                input.parse::<keywords::partial_eq>()?;
-                if input.parse::<Token![=]>().is_ok() {
-                    type_attribute.partial_eq = input.parse::<syn::LitBool>()?.value();
-                } else {
-                    type_attribute.partial_eq = true;
-                }
-            } else {
-                return Err(peek.error());
-            }
-            let _ = input.parse::<Token![,]>();
-        }
-        Ok(type_attribute)
-    }
-}
-/// This attribute is used to generate an compare to str
-///
-/// ```ignore
-/// #[compare_str(equals["uuid", "uuid::Uuid"], contains["uuid"])]
-/// ```
-#[derive(Debug, Default)]
-pub struct CompareToStrAttribute {
-    pub equals: Vec<LitStr>,
-    pub contains: Vec<LitStr>,
-}
-impl Parse for CompareToStrAttribute {
-    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
-        let mut equals = Vec::new();
-        let mut contains = Vec::new();
-        while !input.is_empty() {
-            let peek = input.lookahead1();
-            if peek.peek(keywords::equals) {
-                input.parse::<keywords::equals>()?;
-                let content;
-                bracketed!(content in input);
-                let equals_list = content.parse_terminated(Parse::parse, Token![,])?;
-                equals = equals_list.into_iter().collect();
-            } else if peek.peek(keywords::contains) {
-                input.parse::<keywords::contains>()?;
-                let content;
-                bracketed!(content in input);
-                let list = content.parse_terminated(Parse::parse, Token![,])?;
-                contains = list.into_iter().collect();
-            } else {
-                return Err(peek.error());
-            }
-            consume_comma!(input);
-        }
-        Ok(Self { equals, contains })
-    }
-}
-#[derive(Debug)]
-pub struct CompareToStrVariant {
-    pub name: Ident,
-    pub fields_collection: TokenStream,
-    pub attributes: CompareToStrAttribute,
-}
-impl TryFrom<Variant> for CompareToStrVariant {
-    type Error = Error;
-
-    fn try_from(value: Variant) -> std::result::Result<Self, Self::Error> {
-        let mut compare_attr = CompareToStrAttribute::default();
-        for attr in value.attrs {
-            if attr.path().is_ident("compare_str") {
-                let CompareToStrAttribute { equals, contains } = attr.parse_args()?;
-                compare_attr.equals.extend(equals);
-                compare_attr.contains.extend(contains);
-            }
-        }
-        let fields_collection = match value.fields {
-            Fields::Named(_) => {
-                quote! {
-                    { .. }
-                }
-            }
-            Fields::Unnamed(_) => {
-                quote! {
-                    (..)
-                }
-            }
-            Fields::Unit => {
-                quote! {}
-            }
-        };
-        Ok(Self {
-            name: value.ident,
-            fields_collection,
-            attributes: compare_attr,
-        })
-    }
-}
+use crate::compare_str::attrs::TypeAttribute;
+use crate::compare_str::pattern::{Pattern, PatternKind};
+use crate::compare_str::variant::{claimed_expr, CompareToStrVariant};
+use crate::utils::into_enum;
+use proc_macro2::TokenStream;
+use quote::{quote, TokenStreamExt};
 
-impl ToTokens for CompareToStrVariant {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
-        let Self {
-            name,
-            fields_collection,
-            attributes,
-        } = self;
-        let CompareToStrAttribute { equals, contains } = attributes;
-        if contains.is_empty() {
-            tokens.append_all(quote! {
-                Self::#name #fields_collection =>{
-                    return #(other == #equals)||*;
-                }
-            });
-        } else {
-            tokens.append_all(quote! {
-            Self::#name #fields_collection =>{
-                return if #(other == #equals)||* {
-                    true
-                }else{
-                    #(other.contains(#contains))||*
-                };
-            }
-            });
-        }
-    }
-}
+use syn::{DeriveInput, Error};
+use syn::{LitStr, Result};
 
 pub(crate) fn expand(derive_input: DeriveInput) -> Result<TokenStream> {
     let DeriveInput {
-        ident, data, attrs, ..
+        ident,
+        data,
+        attrs,
+        generics,
+        ..
     } = derive_input;
     let type_attr = attrs
         .into_iter()
@@ -184,64 +37,308 @@ pub(crate) fn expand(derive_input: DeriveInput) -> Result<TokenStream> {
         .variants
         .into_iter()
         .map(|v| {
-            let mut result = CompareToStrVariant::try_from(v);
-            if let Ok(variant) = &mut result {
-                if type_attr.include_variant {
-                    variant
-                        .attributes
-                        .equals
-                        .push(LitStr::new(&variant.name.to_string(), variant.name.span()));
+            let mut variant = CompareToStrVariant::try_from(v)?;
+            if type_attr.include_variant {
+                let name = variant.name.to_string();
+                let mut names = type_attr
+                    .rename_all
+                    .iter()
+                    .map(|casing| casing.apply(&name))
+                    .collect::<Vec<_>>();
+                if names.is_empty() {
+                    names.push(name);
                 }
+                names.sort();
+                names.dedup();
+                for name in names {
+                    let mut pattern =
+                        Pattern::new(PatternKind::Equals, LitStr::new(&name, variant.name.span()))?;
+                    pattern.is_variant_name = true;
+                    variant.attributes.patterns.push(pattern);
+                }
+            }
+            variant.attributes.normalize(type_attr.case_mode)?;
+            if variant.attributes.similar.is_some()
+                && variant
+                    .sources_where(|pattern| pattern.kind == PatternKind::Equals)
+                    .is_empty()
+            {
+                return Err(Error::new(
+                    variant.name.span(),
+                    "`similar` needs an `equals` pattern or `include_variant`",
+                ));
             }
-            result
+            Ok(variant)
         })
         .collect();
     let variants = variants?;
-    let to_lower_case = if type_attr.to_lowercase {
-        quote! {
-            let other = other.to_lowercase();
+    let warnings = overlap::check_overlap(&variants, type_attr.allow_overlap)?;
+    let mut fallbacks = variants.iter().filter(|v| v.attributes.fallback);
+    if let (Some(_), Some(second)) = (fallbacks.next(), fallbacks.next()) {
+        return Err(Error::new(
+            second.name.span(),
+            "Only one variant can be the fallback",
+        ));
+    }
+    let claimed = claimed_expr(&variants, type_attr.case_mode, false);
+    let fallback_claimed = |v: &CompareToStrVariant| v.attributes.fallback.then_some(&claimed);
+    let match_lines = variants
+        .iter()
+        .map(|v| v.create_match_line(type_attr.case_mode, fallback_claimed(v)))
+        .collect::<Vec<_>>();
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let details_lines = variants
+        .iter()
+        .map(|v| v.create_details_line(type_attr.case_mode, fallback_claimed(v)))
+        .collect::<Vec<_>>();
+    let alias_lines = variants.iter().map(|v| {
+        let aliases = v.sources_where(|pattern| pattern.kind == PatternKind::Equals);
+        v.create_static_line(quote! { &[#(#aliases),*] })
+    });
+    let substring_lines = variants.iter().map(|v| {
+        let substrings = v.sources_where(|pattern| pattern.kind == PatternKind::Contains);
+        v.create_static_line(quote! { &[#(#substrings),*] })
+    });
+    let patterns_entries = variants.iter().map(|v| v.create_patterns_entry());
+    let suggestions = variants.iter().flat_map(|v| {
+        let mut names = v
+            .sources_where(|pattern| pattern.kind == PatternKind::Equals)
+            .into_iter()
+            .map(LitStr::value)
+            .collect::<Vec<_>>();
+        // The names of the KeyEnum are accepted by `match_key`
+        if type_attr.key.is_some() && !names.contains(&v.name.to_string()) {
+            names.push(v.name.to_string());
         }
-    } else {
-        quote! {}
-    };
+        let cfg_attrs = &v.cfg_attrs;
+        names
+            .into_iter()
+            .map(move |name| quote! { #(#cfg_attrs)* #name })
+    });
+    let case = type_attr.case_mode.runtime();
     // TODO Improve Doc Comment to show what it is checking for
     let mut result = quote! {
-        impl #ident{
+        #[allow(deprecated)]
+        impl #impl_generics #ident #ty_generics #where_clause {
             #[doc="Compares an enum variant to a str"]
             #[automatically_derived]
             pub fn equals_str(&self, other: impl core::convert::AsRef<str>) -> bool {
                 let other = other.as_ref();
-                #to_lower_case
                     match self {
-                        #(#variants)*
+                        #(#match_lines),*
                     }
             }
+            #[doc="Returns the first pattern of the variant matching the str. Patterns are checked in the same order as `equals_str`"]
+            #[automatically_derived]
+            pub fn match_details<'compare_str>(
+                &'compare_str self,
+                other: &str,
+            ) -> ::core::option::Option<enum_helper::compare_str::StrMatch<'compare_str>> {
+                match self {
+                    #(#details_lines),*
+                }
+                ::core::option::Option::None
+            }
+            #[doc="The `equals` patterns of the variant as written. Including the variant name if `include_variant` is set"]
+            #[automatically_derived]
+            pub fn aliases(&self) -> &'static [&'static str] {
+                match self {
+                    #(#alias_lines),*
+                }
+            }
+            #[doc="The `contains` patterns of the variant as written"]
+            #[automatically_derived]
+            pub fn substrings(&self) -> &'static [&'static str] {
+                match self {
+                    #(#substring_lines),*
+                }
+            }
+            #[doc="Returns the accepted spelling closest to the str. For \"did you mean\" messages"]
+            #[automatically_derived]
+            pub fn suggest(other: &str) -> ::core::option::Option<&'static str> {
+                const CANDIDATES: &[&str] = &[#(#suggestions),*];
+                #case.suggest(other, CANDIDATES)
+            }
+            #[doc="The name of each variant and its patterns in declaration order"]
+            pub const PATTERNS: &'static [(
+                &'static str,
+                &'static [(enum_helper::compare_str::MatchKind, &'static str)],
+            )] = &[#(#patterns_entries),*];
         }
     };
+    result.append_all(quote! {
+        #[automatically_derived]
+        impl #impl_generics enum_helper::compare_str::StrPatterns for #ident #ty_generics #where_clause {
+            const CASE_MODE: enum_helper::compare_str::CaseMode = #case;
+            fn equals_str(&self, other: &str) -> bool {
+                Self::equals_str(self, other)
+            }
+            fn match_str(other: &str) -> ::core::option::Option<Self> {
+                Self::match_str(other)
+            }
+            fn aliases(&self) -> &'static [&'static str] {
+                Self::aliases(self)
+            }
+            fn match_details(
+                &self,
+                other: &str,
+            ) -> ::core::option::Option<enum_helper::compare_str::StrMatch<'_>> {
+                Self::match_details(self, other)
+            }
+        }
+    });
     if type_attr.partial_eq {
         let impl_trait = quote! {
             #[automatically_derived]
-            impl core::cmp::PartialEq<str> for #ident {
+            impl #impl_generics core::cmp::PartialEq<str> for #ident #ty_generics #where_clause {
                 fn eq(&self, other: &str) -> bool {
-                   #to_lower_case
-                    match self {
-                        #(#variants)*
-                    }
+                    self.equals_str(other)
                 }
             }
             #[automatically_derived]
-            impl core::cmp::PartialEq<&str> for #ident {
+            impl #impl_generics core::cmp::PartialEq<&str> for #ident #ty_generics #where_clause {
                 fn eq(&self, other: &&str) -> bool {
-                        let other = *other;
-                                   #to_lower_case
-                    match self {
-                        #(#variants)*
-                    }
+                    self.equals_str(other)
+                }
+            }
+            #[automatically_derived]
+            impl #impl_generics core::cmp::PartialEq<std::string::String> for #ident #ty_generics #where_clause {
+                fn eq(&self, other: &std::string::String) -> bool {
+                    self.equals_str(other)
+                }
+            }
+            #[automatically_derived]
+            impl #impl_generics core::cmp::PartialEq<std::borrow::Cow<'_, str>> for #ident #ty_generics #where_clause {
+                fn eq(&self, other: &std::borrow::Cow<'_, str>) -> bool {
+                    self.equals_str(other)
+                }
+            }
+            #[automatically_derived]
+            impl #impl_generics core::cmp::PartialEq<#ident #ty_generics> for str #where_clause {
+                fn eq(&self, other: &#ident #ty_generics) -> bool {
+                    other.equals_str(self)
+                }
+            }
+            #[automatically_derived]
+            impl #impl_generics core::cmp::PartialEq<#ident #ty_generics> for &str #where_clause {
+                fn eq(&self, other: &#ident #ty_generics) -> bool {
+                    other.equals_str(self)
+                }
+            }
+            #[automatically_derived]
+            impl #impl_generics core::cmp::PartialEq<#ident #ty_generics> for std::string::String #where_clause {
+                fn eq(&self, other: &#ident #ty_generics) -> bool {
+                    other.equals_str(self)
+                }
+            }
+            #[automatically_derived]
+            impl #impl_generics core::cmp::PartialEq<#ident #ty_generics> for std::borrow::Cow<'_, str> #where_clause {
+                fn eq(&self, other: &#ident #ty_generics) -> bool {
+                    other.equals_str(self)
                 }
             }
         };
         result.append_all(impl_trait);
     }
+    if type_attr.bytes {
+        let claimed = claimed_expr(&variants, type_attr.case_mode, true);
+        let bytes_match_lines = variants
+            .iter()
+            .map(|v| {
+                v.create_bytes_match_line(
+                    type_attr.case_mode,
+                    v.attributes.fallback.then_some(&claimed),
+                )
+            })
+            .collect::<Vec<_>>();
+        result.append_all(quote! {
+            #[allow(deprecated)]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #[doc="Compares an enum variant to bytes without converting them to a str"]
+                #[automatically_derived]
+                pub fn equals_bytes(&self, other: impl core::convert::AsRef<[u8]>) -> bool {
+                    let other = other.as_ref();
+                    match self {
+                        #(#bytes_match_lines),*
+                    }
+                }
+                #[doc="Compares an enum variant to the encoded bytes of an OsStr"]
+                #[automatically_derived]
+                pub fn equals_os_str(&self, other: impl core::convert::AsRef<std::ffi::OsStr>) -> bool {
+                    self.equals_bytes(other.as_ref().as_encoded_bytes())
+                }
+            }
+        });
+        if type_attr.partial_eq {
+            result.append_all(quote! {
+                #[automatically_derived]
+                impl #impl_generics core::cmp::PartialEq<[u8]> for #ident #ty_generics #where_clause {
+                    fn eq(&self, other: &[u8]) -> bool {
+                        self.equals_bytes(other)
+                    }
+                }
+                #[automatically_derived]
+                impl #impl_generics core::cmp::PartialEq<&[u8]> for #ident #ty_generics #where_clause {
+                    fn eq(&self, other: &&[u8]) -> bool {
+                        self.equals_bytes(other)
+                    }
+                }
+                #[automatically_derived]
+                impl #impl_generics core::cmp::PartialEq<#ident #ty_generics> for [u8] #where_clause {
+                    fn eq(&self, other: &#ident #ty_generics) -> bool {
+                        other.equals_bytes(self)
+                    }
+                }
+                #[automatically_derived]
+                impl #impl_generics core::cmp::PartialEq<#ident #ty_generics> for &[u8] #where_clause {
+                    fn eq(&self, other: &#ident #ty_generics) -> bool {
+                        other.equals_bytes(self)
+                    }
+                }
+            });
+        }
+    }
+    if cfg!(feature = "syn") {
+        let path_lines = variants
+            .iter()
+            .map(|v| v.create_static_line(v.path_matches_expr(type_attr.case_mode)));
+        result.append_all(quote! {
+            #[allow(deprecated)]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #[doc="Compares an enum variant to a path. `equals` and `path_suffix` patterns are compared to the trailing segments of the path. Generic arguments are ignored"]
+                #[automatically_derived]
+                pub fn equals_path(&self, path: &enum_helper::compare_str::syn::Path) -> bool {
+                    let other = enum_helper::compare_str::path_to_string(path);
+                    #[allow(unused_variables)]
+                    let other = other.as_str();
+                    match self {
+                        #(#path_lines),*
+                    }
+                }
+                #[doc="Compares an enum variant to the path of a type. See `equals_path`"]
+                #[automatically_derived]
+                pub fn equals_type(&self, ty: &enum_helper::compare_str::syn::Type) -> bool {
+                    enum_helper::compare_str::type_path(ty).is_some_and(|path| self.equals_path(path))
+                }
+            }
+        });
+    }
+    result.append_all(const_fn::expand_const(
+        &ident, &generics, &variants, &type_attr,
+    ));
+    result.append_all(reverse::expand_reverse(
+        &ident, &generics, &variants, &type_attr,
+    ));
+    let tracked_files = variants
+        .iter()
+        .flat_map(|v| &v.attributes.tracked_files)
+        .map(|file| {
+            quote! {
+                const _: &[u8] = include_bytes!(#file);
+            }
+        });
+    result.append_all(tracked_files);
+    result.append_all(warnings);
 
     Ok(result)
 }
@@ -0,0 +1,82 @@
+use crate::compare_str::pattern::PatternKind;
+use crate::compare_str::variant::CompareToStrVariant;
+use proc_macro2::TokenStream;
+use quote::quote_spanned;
+use syn::{Error, Result};
+
+/// Rejects patterns declared by more than one variant.
+///
+/// Returns a warning for every pattern of a variant that also matches a pattern of another variant.
+/// The string would then match both variants
+pub(crate) fn check_overlap(
+    variants: &[CompareToStrVariant],
+    allow_overlap: bool,
+) -> Result<Vec<TokenStream>> {
+    let mut warnings = Vec::new();
+    for (index, variant) in variants.iter().enumerate() {
+        for pattern in &variant.attributes.patterns {
+            for other in &variants[..index] {
+                if let Some(duplicate) = other.attributes.patterns.iter().find(|other| {
+                    other.kind == pattern.kind && other.value.value() == pattern.value.value()
+                }) {
+                    return Err(Error::new(
+                        pattern.value.span(),
+                        format!(
+                            "`{}` is already declared by `{}`",
+                            duplicate.display(),
+                            other.name
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+    if allow_overlap {
+        return Ok(warnings);
+    }
+    for variant in variants
+        .iter()
+        .filter(|variant| !variant.attributes.allow_overlap)
+    {
+        for pattern in &variant.attributes.patterns {
+            // A glob has no single string that represents it
+            if pattern.kind == PatternKind::Glob {
+                continue;
+            }
+            let value = pattern.value.value();
+            for other in variants.iter().filter(|other| {
+                other.name != variant.name
+                    && !other.attributes.allow_overlap
+                    && !other.attributes.excepts(&value)
+            }) {
+                if let Some(shadowing) = other
+                    .attributes
+                    .patterns
+                    .iter()
+                    .find(|other| other.matches_literal(&value))
+                {
+                    let message = format!(
+                        "`{}` on `{}` also matches `{}` on `{}`. Add `allow_overlap` if this is intended",
+                        shadowing.display(),
+                        other.name,
+                        pattern.display(),
+                        variant.name
+                    );
+                    warnings.push(warning(pattern.value.span(), &message));
+                }
+            }
+        }
+    }
+    Ok(warnings)
+}
+/// Stable proc macros can not emit warnings. So a deprecated constant is used instead
+fn warning(span: proc_macro2::Span, message: &str) -> TokenStream {
+    quote_spanned! {span=>
+        const _: () = {
+            #[deprecated(note = #message)]
+            #[allow(non_upper_case_globals)]
+            const compare_str_overlap: () = ();
+            compare_str_overlap
+        };
+    }
+}
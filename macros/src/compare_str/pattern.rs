@@ -0,0 +1,215 @@
+use crate::compare_str::case_mode::CaseMode;
+use crate::compare_str::glob::Glob;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Error, LitByteStr, LitStr, Result};
+
+/// The kind of check a pattern performs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatternKind {
+    /// `equals[...]` The string is the pattern
+    Equals,
+    /// `contains[...]` The string contains the pattern
+    Contains,
+    /// `starts_with[...]`
+    StartsWith,
+    /// `ends_with[...]`
+    EndsWith,
+    /// `word[...]` The string contains the pattern as a whole word
+    Word,
+    /// `path_suffix[...]` The last segments of a `::` separated path are the pattern
+    PathSuffix,
+    /// `glob[...]` The string matches the glob pattern
+    Glob,
+}
+impl PatternKind {
+    /// The name used in the attribute
+    pub fn name(&self) -> &'static str {
+        match self {
+            PatternKind::Equals => "equals",
+            PatternKind::Contains => "contains",
+            PatternKind::StartsWith => "starts_with",
+            PatternKind::EndsWith => "ends_with",
+            PatternKind::Word => "word",
+            PatternKind::PathSuffix => "path_suffix",
+            PatternKind::Glob => "glob",
+        }
+    }
+}
+/// A single literal of a compare_str attribute
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    pub kind: PatternKind,
+    /// The literal normalized for the case mode
+    pub value: LitStr,
+    /// The literal as written
+    pub source: LitStr,
+    /// Added by `include_variant`
+    pub is_variant_name: bool,
+}
+impl Pattern {
+    pub fn new(kind: PatternKind, value: LitStr) -> Result<Self> {
+        let inner = value.value();
+        match kind {
+            PatternKind::Word if inner.is_empty() => {
+                return Err(Error::new(value.span(), "A word pattern can not be empty"));
+            }
+            PatternKind::PathSuffix if inner.is_empty() || inner.starts_with("::") => {
+                return Err(Error::new(
+                    value.span(),
+                    "A path_suffix pattern must start with a path segment",
+                ));
+            }
+            PatternKind::Glob => {
+                Glob::parse(&inner).map_err(|err| Error::new(value.span(), err))?;
+            }
+            _ => {}
+        }
+        Ok(Self {
+            kind,
+            source: value.clone(),
+            value,
+            is_variant_name: false,
+        })
+    }
+    /// Normalizes the literal for the case mode
+    pub fn normalize(&mut self, case_mode: CaseMode) -> Result<()> {
+        self.value = Self::new(self.kind, case_mode.normalize(&self.value))?.value;
+        Ok(())
+    }
+    /// Checks if the pattern matches a normalized string at macro time
+    pub fn matches_literal(&self, value: &str) -> bool {
+        let pattern = self.value.value();
+        match self.kind {
+            PatternKind::Equals => value == pattern,
+            PatternKind::Contains => value.contains(&pattern),
+            PatternKind::StartsWith => value.starts_with(&pattern),
+            PatternKind::EndsWith => value.ends_with(&pattern),
+            PatternKind::Word => value.match_indices(&pattern).any(|(start, found)| {
+                let is_word_char = |c: char| c.is_alphanumeric() || c == '_';
+                let before = value[..start].chars().next_back();
+                let after = value[start + found.len()..].chars().next();
+                !before.is_some_and(is_word_char) && !after.is_some_and(is_word_char)
+            }),
+            PatternKind::PathSuffix => value
+                .strip_suffix(&pattern)
+                .is_some_and(|prefix| prefix.is_empty() || prefix.ends_with("::")),
+            PatternKind::Glob => Glob::parse(&pattern)
+                .expect("Validated in Pattern::new")
+                .matches(value),
+        }
+    }
+    /// A readable form of the pattern. `equals["uuid"]`
+    pub fn display(&self) -> String {
+        format!("{}[{:?}]", self.kind.name(), self.value.value())
+    }
+    /// An expression checking if `value` matches the pattern
+    pub fn matches_expr(&self, case_mode: CaseMode, value: &TokenStream) -> TokenStream {
+        let lit = &self.value;
+        let case = case_mode.runtime();
+        match (self.kind, case_mode) {
+            (PatternKind::Equals, CaseMode::Sensitive) => quote! { #value == #lit },
+            (PatternKind::Equals, CaseMode::AsciiCaseInsensitive) => {
+                quote! { #value.eq_ignore_ascii_case(#lit) }
+            }
+            (PatternKind::Equals, CaseMode::CaseFold) => quote! { #case.eq(#value, #lit) },
+            (PatternKind::Contains, CaseMode::Sensitive) => quote! { #value.contains(#lit) },
+            (PatternKind::Contains, _) => quote! { #case.contains(#value, #lit) },
+            (PatternKind::StartsWith, CaseMode::Sensitive) => {
+                quote! { #value.starts_with(#lit) }
+            }
+            (PatternKind::StartsWith, _) => quote! { #case.starts_with(#value, #lit) },
+            (PatternKind::EndsWith, CaseMode::Sensitive) => quote! { #value.ends_with(#lit) },
+            (PatternKind::EndsWith, _) => quote! { #case.ends_with(#value, #lit) },
+            (PatternKind::Word, _) => quote! { #case.contains_word(#value, #lit) },
+            (PatternKind::PathSuffix, _) => quote! { #case.ends_with_path(#value, #lit) },
+            (PatternKind::Glob, _) => Glob::parse(&lit.value())
+                .expect("Validated in Pattern::new")
+                .matches_expr(case_mode, value),
+        }
+    }
+    /// An expression checking if the bytes `value` match the pattern
+    ///
+    /// `word` and `glob` patterns only match valid UTF-8
+    pub fn matches_bytes_expr(&self, case_mode: CaseMode, value: &TokenStream) -> TokenStream {
+        let lit = &self.value;
+        let case = case_mode.runtime();
+        match (self.kind, case_mode) {
+            (PatternKind::Equals, CaseMode::Sensitive) => {
+                let bytes = LitByteStr::new(lit.value().as_bytes(), lit.span());
+                quote! { #value == #bytes }
+            }
+            (PatternKind::Equals, CaseMode::AsciiCaseInsensitive) => {
+                let bytes = LitByteStr::new(lit.value().as_bytes(), lit.span());
+                quote! { #value.eq_ignore_ascii_case(#bytes) }
+            }
+            (PatternKind::Equals, CaseMode::CaseFold) => quote! { #case.eq_bytes(#value, #lit) },
+            (PatternKind::Contains, _) => quote! { #case.contains_bytes(#value, #lit) },
+            (PatternKind::StartsWith, _) => quote! { #case.starts_with_bytes(#value, #lit) },
+            (PatternKind::EndsWith, _) => quote! { #case.ends_with_bytes(#value, #lit) },
+            (PatternKind::PathSuffix, _) => {
+                quote! { #case.ends_with_path_bytes(#value, #lit) }
+            }
+            (PatternKind::Word | PatternKind::Glob, _) => {
+                let str_value = quote!(value);
+                let matches = self.matches_expr(case_mode, &str_value);
+                quote! {
+                    ::core::str::from_utf8(#value).is_ok_and(|#str_value| #matches)
+                }
+            }
+        }
+    }
+    /// The matching `enum_helper::compare_str::MatchKind`
+    pub fn match_kind(&self) -> TokenStream {
+        let kind = match self.kind {
+            PatternKind::Equals if self.is_variant_name => "VariantName",
+            PatternKind::Equals => "Equals",
+            PatternKind::Contains => "Contains",
+            PatternKind::StartsWith => "StartsWith",
+            PatternKind::EndsWith => "EndsWith",
+            PatternKind::Word => "Word",
+            PatternKind::PathSuffix => "PathSuffix",
+            PatternKind::Glob => "Glob",
+        };
+        let kind = format_ident!("{}", kind);
+        quote! { enum_helper::compare_str::MatchKind::#kind }
+    }
+    /// An expression returning the byte range of `value` matching the pattern. `Option<Range<usize>>`
+    pub fn range_expr(&self, case_mode: CaseMode, value: &TokenStream) -> TokenStream {
+        let lit = &self.value;
+        let case = case_mode.runtime();
+        match self.kind {
+            PatternKind::Equals | PatternKind::Glob => {
+                let matches = self.matches_expr(case_mode, value);
+                quote! { (#matches).then(|| 0..#value.len()) }
+            }
+            PatternKind::Contains => quote! { #case.find(#value, #lit) },
+            PatternKind::StartsWith => quote! { #case.prefix_len(#value, #lit).map(|len| 0..len) },
+            PatternKind::EndsWith => quote! {
+                #case.suffix_len(#value, #lit).map(|len| #value.len() - len..#value.len())
+            },
+            PatternKind::Word => quote! { #case.find_word(#value, #lit) },
+            PatternKind::PathSuffix => quote! { #case.find_path_suffix(#value, #lit) },
+        }
+    }
+    /// A const expression checking if the bytes `value` match the pattern.
+    ///
+    /// None for kinds that can not be checked in a const fn. Only `equals`, `starts_with` and `ends_with` can
+    pub fn const_matches_expr(
+        &self,
+        case_mode: CaseMode,
+        value: &TokenStream,
+    ) -> Option<TokenStream> {
+        let function = match self.kind {
+            PatternKind::Equals => quote! { const_eq },
+            PatternKind::StartsWith => quote! { const_starts_with },
+            PatternKind::EndsWith => quote! { const_ends_with },
+            _ => return None,
+        };
+        let bytes = LitByteStr::new(self.value.value().as_bytes(), self.value.span());
+        let ignore_ascii_case = case_mode == CaseMode::AsciiCaseInsensitive;
+        Some(quote! {
+            enum_helper::compare_str::#function(#value, #bytes, #ignore_ascii_case)
+        })
+    }
+}
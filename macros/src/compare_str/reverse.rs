@@ -0,0 +1,213 @@
+use crate::compare_str::attrs::TypeAttribute;
+use crate::compare_str::case_mode::CaseMode;
+use crate::compare_str::dispatch::{self, MultiPattern};
+use crate::compare_str::pattern::{Pattern, PatternKind};
+use crate::compare_str::variant::{claimed_expr, CompareToStrVariant};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{GenericArgument, Generics, PathArguments, Type};
+
+/// Checks if the key type has a `'static` lifetime argument. `Keys<'static>`
+fn is_static_key(key: &Type) -> bool {
+    let Type::Path(path) = key else {
+        return false;
+    };
+    path.path.segments.iter().any(|segment| {
+        let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+            return false;
+        };
+        arguments.args.iter().any(|argument| {
+            matches!(argument, GenericArgument::Lifetime(lifetime) if lifetime.ident == "static")
+        })
+    })
+}
+/// Generates the checks returning the first variant matching `other`.
+///
+/// Exact matches of every variant are checked first. Then the other patterns. Each in declaration order.
+///
+/// Exact matches are dispatched on the bytes of the string.
+/// Large sets of `contains` patterns are searched in a single pass with [MultiPattern].
+///
+/// The fallback variant is returned last if `claimed` is false
+fn first_match(
+    variants: &[&CompareToStrVariant],
+    claimed: &TokenStream,
+    type_attr: &TypeAttribute,
+    value: impl Fn(&CompareToStrVariant) -> TokenStream,
+) -> TokenStream {
+    let case_mode = type_attr.case_mode;
+    let exact = match dispatch::exact_dispatch(variants, case_mode, &value) {
+        Some(exact) => exact,
+        None => {
+            let checks = variants.iter().filter_map(|variant| {
+                let matches = variant.exact_matches_expr(case_mode)?;
+                Some(return_if(variant, matches, &value))
+            });
+            quote! { #(#checks)* }
+        }
+    };
+    // Variants with cfg attributes are left out of the automaton. Their index may not exist
+    // Variants with an `except` list are also left out. The automaton only reports the lowest match
+    let is_multi_pattern = |pattern: &Pattern, variant: &CompareToStrVariant| {
+        pattern.kind == PatternKind::Contains
+            && variant.cfg_attrs.is_empty()
+            && variant.attributes.except.is_empty()
+    };
+    let multi_patterns = variants
+        .iter()
+        .enumerate()
+        .flat_map(|(index, variant)| {
+            variant
+                .attributes
+                .patterns
+                .iter()
+                .filter(|pattern| is_multi_pattern(pattern, variant))
+                .map(move |pattern| (pattern.value.value(), index as u32))
+        })
+        .collect::<Vec<_>>();
+    let use_multi_pattern = case_mode != CaseMode::CaseFold
+        && multi_patterns.len() >= dispatch::MULTI_PATTERN_THRESHOLD;
+    let mut others = Vec::new();
+    if use_multi_pattern {
+        let matcher = MultiPattern::build(&multi_patterns).to_tokens();
+        let case = case_mode.runtime();
+        others.push(quote! {
+            static MULTI_PATTERN: enum_helper::compare_str::MultiPattern = #matcher;
+            let contains_match = MULTI_PATTERN.lowest_match(other, #case);
+        });
+    }
+    for (index, variant) in variants.iter().enumerate() {
+        let matches = if use_multi_pattern {
+            let rest = variant.matches_expr_where(case_mode, |pattern| {
+                pattern.kind != PatternKind::Equals && !is_multi_pattern(pattern, variant)
+            });
+            let has_multi_pattern = variant
+                .attributes
+                .patterns
+                .iter()
+                .any(|pattern| is_multi_pattern(pattern, variant));
+            let index = index as u32;
+            match (has_multi_pattern, rest) {
+                (true, Some(rest)) => Some(quote! { contains_match == #index || #rest }),
+                (true, None) => Some(quote! { contains_match == #index }),
+                (false, rest) => rest,
+            }
+        } else {
+            variant.other_matches_expr(case_mode)
+        };
+        let checks = [matches, variant.predicate_expr(case_mode, false)];
+        let checks = checks.into_iter().flatten().collect::<Vec<_>>();
+        if !checks.is_empty() {
+            let matches = variant.guard(case_mode, false, quote! { #(#checks)||* });
+            others.push(return_if(variant, matches, &value));
+        }
+    }
+    // Near misses are only accepted if no pattern matched
+    for variant in variants {
+        if let Some(matches) = variant.similar_expr(case_mode, false) {
+            let matches = variant.guard(case_mode, false, matches);
+            others.push(return_if(variant, matches, &value));
+        }
+    }
+    if let Some(fallback) = variants.iter().find(|variant| variant.attributes.fallback) {
+        let matches = fallback.guard(case_mode, false, quote! { !(#claimed) });
+        others.push(return_if(fallback, matches, &value));
+    }
+    if exact.is_empty() && others.is_empty() {
+        quote! {
+            let _ = other;
+            ::core::option::Option::None
+        }
+    } else {
+        quote! {
+            #exact
+            #(#others)*
+            ::core::option::Option::None
+        }
+    }
+}
+fn return_if(
+    variant: &CompareToStrVariant,
+    matches: TokenStream,
+    value: &dyn Fn(&CompareToStrVariant) -> TokenStream,
+) -> TokenStream {
+    let cfg_attrs = &variant.cfg_attrs;
+    let value = value(variant);
+    quote! {
+        #(#cfg_attrs)*
+        if #matches {
+            return ::core::option::Option::Some(#value);
+        }
+    }
+}
+
+pub(crate) fn expand_reverse(
+    ident: &Ident,
+    generics: &Generics,
+    variants: &[CompareToStrVariant],
+    type_attr: &TypeAttribute,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let claimed = claimed_expr(variants, type_attr.case_mode, false);
+    let unit_variants = variants.iter().filter(|v| v.is_unit).collect::<Vec<_>>();
+    let match_str = first_match(&unit_variants, &claimed, type_attr, |variant| {
+        let name = &variant.name;
+        quote! { Self::#name }
+    });
+    let mut result = quote! {
+        #[automatically_derived]
+        #[allow(deprecated)]
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Finds the unit variant matching the string.
+            ///
+            /// Exact matches are checked before any other pattern. Ties are broken by declaration order
+            pub fn match_str(other: &str) -> ::core::option::Option<Self> {
+                #match_str
+            }
+        }
+    };
+    if let Some(key) = &type_attr.key {
+        let all_variants = variants.iter().collect::<Vec<_>>();
+        // A `Keys<'static>` can not borrow the string. So the default variant gets a copy
+        let default_value = if is_static_key(key) {
+            quote! { ::core::convert::From::from(::std::borrow::ToOwned::to_owned(other)) }
+        } else {
+            quote! { ::core::convert::From::from(other) }
+        };
+        let match_key = first_match(&all_variants, &claimed, type_attr, |variant| {
+            let name = &variant.name;
+            if variant.is_key_default {
+                quote! { <#key>::#name(#default_value) }
+            } else {
+                quote! { <#key>::#name }
+            }
+        });
+        result.extend(quote! {
+            #[automatically_derived]
+            #[allow(deprecated)]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// Finds the key of the variant matching the string.
+                ///
+                /// Exact matches are checked before any other pattern. Ties are broken by declaration order
+                pub fn match_key(other: &str) -> ::core::option::Option<#key> {
+                    #match_key
+                }
+            }
+        });
+    }
+    if type_attr.from_str {
+        result.extend(quote! {
+            #[automatically_derived]
+            impl #impl_generics ::core::str::FromStr for #ident #ty_generics #where_clause {
+                type Err = enum_helper::compare_str::ParseVariantError;
+                fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                    Self::match_str(s).ok_or_else(|| {
+                        enum_helper::compare_str::ParseVariantError::new(s)
+                            .with_suggestion(Self::suggest(s))
+                    })
+                }
+            }
+        });
+    }
+    result
+}
@@ -0,0 +1,566 @@
+use crate::compare_str::attrs::CompareToStrAttribute;
+use crate::compare_str::case_mode::CaseMode;
+use crate::compare_str::pattern::{Pattern, PatternKind};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Error, Fields, LitStr, Member, Meta, Path, Result, Token, Variant};
+
+#[derive(Debug)]
+pub struct CompareToStrVariant {
+    pub name: Ident,
+    pub fields_collection: TokenStream,
+    pub attributes: CompareToStrAttribute,
+    /// `cfg` and `cfg_attr` attributes of the variant. Added to the generated match arm
+    pub cfg_attrs: Vec<Attribute>,
+    pub is_unit: bool,
+    /// The variant is the `#[enum_of_keys(default)]` variant. Its key holds the string
+    pub is_key_default: bool,
+    /// Pattern binding the `field` to `field_value`. Replaces `fields_collection` in the match arm
+    pub field_pattern: Option<TokenStream>,
+}
+impl TryFrom<Variant> for CompareToStrVariant {
+    type Error = Error;
+
+    fn try_from(value: Variant) -> std::result::Result<Self, Self::Error> {
+        let mut compare_attr = CompareToStrAttribute::default();
+        let mut cfg_attrs = Vec::new();
+        let mut is_key_default = false;
+        for attr in value.attrs {
+            if attr.path().is_ident("compare_str") {
+                compare_attr.extend(attr.parse_args()?);
+            } else if attr.path().is_ident("enum_of_keys") {
+                let options =
+                    attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+                is_key_default = options.iter().any(|meta| meta.path().is_ident("default"));
+            } else if attr.path().is_ident("cfg") || attr.path().is_ident("cfg_attr") {
+                cfg_attrs.push(attr);
+            }
+        }
+        let is_unit = matches!(value.fields, Fields::Unit);
+        let field_pattern = compare_attr
+            .field
+            .as_ref()
+            .map(|field| field_pattern(&value.ident, &value.fields, field.as_ref()))
+            .transpose()?;
+        let fields_collection = match value.fields {
+            Fields::Named(_) => {
+                quote! {
+                    { .. }
+                }
+            }
+            Fields::Unnamed(_) => {
+                quote! {
+                    (..)
+                }
+            }
+            Fields::Unit => {
+                quote! {}
+            }
+        };
+        Ok(Self {
+            name: value.ident,
+            fields_collection,
+            attributes: compare_attr,
+            cfg_attrs,
+            is_unit,
+            is_key_default,
+            field_pattern,
+        })
+    }
+}
+/// Creates the pattern binding the selected field to `field_value`
+fn field_pattern(name: &Ident, fields: &Fields, field: Option<&Member>) -> Result<TokenStream> {
+    let field = match (field, fields) {
+        (_, Fields::Unit) => {
+            return Err(Error::new(
+                name.span(),
+                "`field` can not be used on a unit variant",
+            ))
+        }
+        (Some(field), _) => field.clone(),
+        (None, Fields::Named(named)) if named.named.len() == 1 => {
+            Member::Named(named.named[0].ident.clone().expect("Named Field"))
+        }
+        (None, Fields::Unnamed(unnamed)) if unnamed.unnamed.len() == 1 => Member::Unnamed(0.into()),
+        (None, _) => {
+            return Err(Error::new(
+                name.span(),
+                "The variant has multiple fields. Use `field = name` to pick one",
+            ))
+        }
+    };
+    match (&field, fields) {
+        (Member::Named(ident), Fields::Named(named))
+            if named.named.iter().any(|f| f.ident.as_ref() == Some(ident)) =>
+        {
+            Ok(quote! { { #ident: field_value, .. } })
+        }
+        (Member::Unnamed(index), Fields::Unnamed(unnamed))
+            if (index.index as usize) < unnamed.unnamed.len() =>
+        {
+            let skipped = (0..index.index).map(|_| quote!(_));
+            Ok(quote! { ( #(#skipped,)* field_value, .. ) })
+        }
+        _ => Err(Error::new_spanned(field, "The variant has no such field")),
+    }
+}
+
+impl CompareToStrVariant {
+    /// The expression checking if `other` matches this variant
+    ///
+    /// Includes the `field` check. So it must be used in an arm created by [Self::create_match_line].
+    /// `claimed` is only passed for the fallback variant. See [claimed_expr]
+    pub fn matches_expr(&self, case_mode: CaseMode, claimed: Option<&TokenStream>) -> TokenStream {
+        // Equals is the cheapest check so it goes first
+        let checks = [
+            self.exact_matches_expr(case_mode),
+            self.other_matches_expr(case_mode),
+            self.predicate_expr(case_mode, false),
+            self.similar_expr(case_mode, false),
+            self.field_matches_expr(case_mode),
+            self.self_predicate_expr(case_mode, false),
+            claimed.map(|claimed| quote! { !(#claimed) }),
+        ];
+        self.guard(case_mode, false, or(checks.into_iter().flatten()))
+    }
+    /// The expression calling the `with` predicates. None if there are none
+    pub fn predicate_expr(&self, case_mode: CaseMode, bytes: bool) -> Option<TokenStream> {
+        call_predicates(&self.attributes.with, case_mode, bytes, |path| {
+            quote! { #path(&other) }
+        })
+    }
+    /// The expression checking if `other` is `similar` to an `equals` pattern. None if `similar` is not set
+    pub fn similar_expr(&self, case_mode: CaseMode, bytes: bool) -> Option<TokenStream> {
+        let closest = self.closest_expr(case_mode)?;
+        if bytes {
+            Some(quote! {
+                ::core::str::from_utf8(other).is_ok_and(|other| #closest.is_some())
+            })
+        } else {
+            Some(quote! { #closest.is_some() })
+        }
+    }
+    /// The expression returning the index of the closest `equals` pattern within the `similar` threshold
+    fn closest_expr(&self, case_mode: CaseMode) -> Option<TokenStream> {
+        let threshold = self.attributes.similar?;
+        let case = case_mode.runtime();
+        let aliases = self
+            .attributes
+            .patterns
+            .iter()
+            .filter(|pattern| pattern.kind == PatternKind::Equals)
+            .map(|pattern| &pattern.value);
+        Some(quote! { #case.closest(other, &[#(#aliases),*], #threshold) })
+    }
+    /// The expression calling the `with_self` predicates. None if there are none
+    pub fn self_predicate_expr(&self, case_mode: CaseMode, bytes: bool) -> Option<TokenStream> {
+        call_predicates(&self.attributes.with_self, case_mode, bytes, |path| {
+            quote! { #path(self, &other) }
+        })
+    }
+    /// The expression comparing `other` to the `field`. None if there is no field
+    pub fn field_matches_expr(&self, case_mode: CaseMode) -> Option<TokenStream> {
+        self.field_pattern.as_ref()?;
+        let case = case_mode.runtime();
+        Some(quote! {
+            #case.eq_unnormalized(other, ::core::convert::AsRef::<str>::as_ref(field_value))
+        })
+    }
+    /// The expression checking the `equals` patterns. None if there are none
+    pub fn exact_matches_expr(&self, case_mode: CaseMode) -> Option<TokenStream> {
+        self.matches_expr_where(case_mode, |pattern| pattern.kind == PatternKind::Equals)
+    }
+    /// The expression checking every pattern that is not `equals`. None if there are none
+    pub fn other_matches_expr(&self, case_mode: CaseMode) -> Option<TokenStream> {
+        self.matches_expr_where(case_mode, |pattern| pattern.kind != PatternKind::Equals)
+    }
+    pub fn matches_expr_where(
+        &self,
+        case_mode: CaseMode,
+        filter: impl Fn(&Pattern) -> bool,
+    ) -> Option<TokenStream> {
+        let other = quote!(other);
+        let checks = self
+            .attributes
+            .patterns
+            .iter()
+            .filter(|pattern| filter(pattern))
+            .map(|pattern| pattern.matches_expr(case_mode, &other))
+            .collect::<Vec<_>>();
+        if checks.is_empty() {
+            None
+        } else {
+            Some(quote! { #(#checks)||* })
+        }
+    }
+    /// The expression checking if the path string `other` matches this variant.
+    ///
+    /// Only `equals` and `path_suffix` patterns are checked. Each compares against the trailing segments of the path
+    pub fn path_matches_expr(&self, case_mode: CaseMode) -> TokenStream {
+        let case = case_mode.runtime();
+        let checks = self
+            .attributes
+            .patterns
+            .iter()
+            .filter(|pattern| matches!(pattern.kind, PatternKind::Equals | PatternKind::PathSuffix))
+            .map(|pattern| {
+                let lit = &pattern.value;
+                quote! { #case.ends_with_path(other, #lit) }
+            });
+        self.guard(case_mode, false, or(checks))
+    }
+    /// The expression checking the `except` list. None if there is none
+    pub fn except_expr(&self, case_mode: CaseMode, bytes: bool) -> Option<TokenStream> {
+        let other = quote!(other);
+        let checks = self
+            .attributes
+            .except
+            .iter()
+            .map(|pattern| {
+                if bytes {
+                    pattern.matches_bytes_expr(case_mode, &other)
+                } else {
+                    pattern.matches_expr(case_mode, &other)
+                }
+            })
+            .collect::<Vec<_>>();
+        if checks.is_empty() {
+            None
+        } else {
+            Some(quote! { #(#checks)||* })
+        }
+    }
+    /// Wraps `matches` so it is false for strings in the `except` list
+    pub fn guard(&self, case_mode: CaseMode, bytes: bool, matches: TokenStream) -> TokenStream {
+        match self.except_expr(case_mode, bytes) {
+            Some(except) => quote! { !(#except) && (#matches) },
+            None => matches,
+        }
+    }
+    /// The expression checking if the bytes `other` match this variant
+    ///
+    /// Like [Self::matches_expr] it must be used in an arm created by [Self::create_bytes_match_line]
+    pub fn bytes_matches_expr(
+        &self,
+        case_mode: CaseMode,
+        claimed: Option<&TokenStream>,
+    ) -> TokenStream {
+        let mut checks = self
+            .bytes_patterns_expr(case_mode)
+            .into_iter()
+            .collect::<Vec<_>>();
+        if self.field_pattern.is_some() {
+            let case = case_mode.runtime();
+            checks.push(quote! {
+                #case.eq_unnormalized_bytes(other, ::core::convert::AsRef::<str>::as_ref(field_value))
+            });
+        }
+        checks.extend(self.self_predicate_expr(case_mode, true));
+        checks.extend(claimed.map(|claimed| quote! { !(#claimed) }));
+        self.guard(case_mode, true, or(checks))
+    }
+    /// The expression checking every pattern and `with` predicate against the bytes `other`. None if there are none
+    fn bytes_patterns_expr(&self, case_mode: CaseMode) -> Option<TokenStream> {
+        let other = quote!(other);
+        let (exact, others): (Vec<_>, Vec<_>) = self
+            .attributes
+            .patterns
+            .iter()
+            .partition(|pattern| pattern.kind == PatternKind::Equals);
+        let checks = exact
+            .into_iter()
+            .chain(others)
+            .map(|pattern| pattern.matches_bytes_expr(case_mode, &other))
+            .chain(self.predicate_expr(case_mode, true))
+            .chain(self.similar_expr(case_mode, true))
+            .collect::<Vec<_>>();
+        if checks.is_empty() {
+            None
+        } else {
+            Some(quote! { #(#checks)||* })
+        }
+    }
+    /// A match arm returning the first pattern matching `other` as a `StrMatch`
+    ///
+    /// Patterns are checked in the same order as [Self::matches_expr]
+    pub fn create_details_line(
+        &self,
+        case_mode: CaseMode,
+        claimed: Option<&TokenStream>,
+    ) -> TokenStream {
+        let other = quote!(other);
+        let (exact, others): (Vec<_>, Vec<_>) = self
+            .attributes
+            .patterns
+            .iter()
+            .partition(|pattern| pattern.kind == PatternKind::Equals);
+        let mut checks = exact
+            .into_iter()
+            .chain(others)
+            .map(|pattern| {
+                let range = pattern.range_expr(case_mode, &other);
+                let kind = pattern.match_kind();
+                let source = &pattern.source;
+                quote! {
+                    if let ::core::option::Option::Some(range) = #range {
+                        return ::core::option::Option::Some(enum_helper::compare_str::StrMatch {
+                            kind: #kind,
+                            pattern: #source,
+                            range,
+                        });
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+        let predicate_details = |paths: &[Path], call: fn(&Path) -> TokenStream| {
+            paths
+                .iter()
+                .map(|path| {
+                    let matches =
+                        call_predicates(std::slice::from_ref(path), case_mode, false, call);
+                    let name = path_display(path);
+                    quote! {
+                        if #matches {
+                            return ::core::option::Option::Some(enum_helper::compare_str::StrMatch {
+                                kind: enum_helper::compare_str::MatchKind::Predicate,
+                                pattern: #name,
+                                range: 0..other.len(),
+                            });
+                        }
+                    }
+                })
+                .collect::<Vec<_>>()
+        };
+        checks.extend(predicate_details(&self.attributes.with, |path| {
+            quote! { #path(&other) }
+        }));
+        if let Some(closest) = self.closest_expr(case_mode) {
+            let sources = self.sources_where(|pattern| pattern.kind == PatternKind::Equals);
+            checks.push(quote! {
+                if let ::core::option::Option::Some(index) = #closest {
+                    const SOURCES: &[&str] = &[#(#sources),*];
+                    return ::core::option::Option::Some(enum_helper::compare_str::StrMatch {
+                        kind: enum_helper::compare_str::MatchKind::Similar,
+                        pattern: SOURCES[index],
+                        range: 0..other.len(),
+                    });
+                }
+            });
+        }
+        if self.field_pattern.is_some() {
+            let case = case_mode.runtime();
+            checks.push(quote! {
+                let field_value = ::core::convert::AsRef::<str>::as_ref(field_value);
+                if #case.eq_unnormalized(other, field_value) {
+                    return ::core::option::Option::Some(enum_helper::compare_str::StrMatch {
+                        kind: enum_helper::compare_str::MatchKind::Field,
+                        pattern: field_value,
+                        range: 0..other.len(),
+                    });
+                }
+            });
+        }
+        checks.extend(predicate_details(&self.attributes.with_self, |path| {
+            quote! { #path(self, &other) }
+        }));
+        if let Some(claimed) = claimed {
+            checks.push(quote! {
+                if !(#claimed) {
+                    return ::core::option::Option::Some(enum_helper::compare_str::StrMatch {
+                        kind: enum_helper::compare_str::MatchKind::Fallback,
+                        pattern: "",
+                        range: 0..other.len(),
+                    });
+                }
+            });
+        }
+        if let Some(except) = self.except_expr(case_mode, false) {
+            checks.insert(
+                0,
+                quote! {
+                    if #except {
+                        return ::core::option::Option::None;
+                    }
+                },
+            );
+        }
+        self.match_line(quote! { { #(#checks)* } })
+    }
+    /// The patterns as written. In declaration order
+    pub fn sources_where(&self, filter: impl Fn(&Pattern) -> bool) -> Vec<&LitStr> {
+        self.attributes
+            .patterns
+            .iter()
+            .filter(|pattern| filter(pattern))
+            .map(|pattern| &pattern.source)
+            .collect()
+    }
+    /// A match arm returning `value` that does not bind any field
+    pub fn create_static_line(&self, value: TokenStream) -> TokenStream {
+        let Self {
+            name,
+            fields_collection,
+            cfg_attrs,
+            ..
+        } = self;
+        quote! {
+            #(#cfg_attrs)*
+            Self::#name #fields_collection => #value
+        }
+    }
+    /// An entry of the `PATTERNS` table
+    pub fn create_patterns_entry(&self) -> TokenStream {
+        let Self {
+            name, cfg_attrs, ..
+        } = self;
+        let name = name.to_string();
+        let patterns = self.attributes.patterns.iter().map(|pattern| {
+            let kind = pattern.match_kind();
+            let source = &pattern.source;
+            quote! { (#kind, #source) }
+        });
+        let predicates = self
+            .attributes
+            .with
+            .iter()
+            .chain(&self.attributes.with_self)
+            .map(|path| {
+                let name = path_display(path);
+                quote! { (enum_helper::compare_str::MatchKind::Predicate, #name) }
+            });
+        let patterns = patterns.chain(predicates);
+        quote! {
+            #(#cfg_attrs)*
+            (#name, &[#(#patterns),*])
+        }
+    }
+    pub fn create_match_line(
+        &self,
+        case_mode: CaseMode,
+        claimed: Option<&TokenStream>,
+    ) -> TokenStream {
+        self.match_line(self.matches_expr(case_mode, claimed))
+    }
+    pub fn create_bytes_match_line(
+        &self,
+        case_mode: CaseMode,
+        claimed: Option<&TokenStream>,
+    ) -> TokenStream {
+        self.match_line(self.bytes_matches_expr(case_mode, claimed))
+    }
+    fn match_line(&self, matches: TokenStream) -> TokenStream {
+        let Self {
+            name,
+            fields_collection,
+            cfg_attrs,
+            field_pattern,
+            ..
+        } = self;
+        let fields_collection = field_pattern.as_ref().unwrap_or(fields_collection);
+        quote! {
+            #(#cfg_attrs)*
+            Self::#name #fields_collection => #matches
+        }
+    }
+}
+/// Joins the checks with `||`. `false` if there are none
+fn or(checks: impl IntoIterator<Item = TokenStream>) -> TokenStream {
+    let checks = checks.into_iter().collect::<Vec<_>>();
+    if checks.is_empty() {
+        quote! { false }
+    } else {
+        quote! { #(#checks)||* }
+    }
+}
+/// An expression checking if a pattern of a variant other than the fallback matches `other`.
+///
+/// The `field` of a variant is not checked. There is no value to read it from.
+/// Statements are used so `cfg` attributes can be placed on each variant
+pub fn claimed_expr(
+    variants: &[CompareToStrVariant],
+    case_mode: CaseMode,
+    bytes: bool,
+) -> TokenStream {
+    let checks = variants
+        .iter()
+        .filter(|variant| !variant.attributes.fallback)
+        .filter_map(|variant| {
+            let matches = if bytes {
+                variant.bytes_patterns_expr(case_mode)?
+            } else {
+                let checks = [
+                    variant.exact_matches_expr(case_mode),
+                    variant.other_matches_expr(case_mode),
+                    variant.predicate_expr(case_mode, false),
+                    variant.similar_expr(case_mode, false),
+                ];
+                let checks = checks.into_iter().flatten().collect::<Vec<_>>();
+                if checks.is_empty() {
+                    return None;
+                }
+                or(checks)
+            };
+            let matches = variant.guard(case_mode, bytes, matches);
+            let cfg_attrs = &variant.cfg_attrs;
+            Some(quote! {
+                #(#cfg_attrs)*
+                if #matches {
+                    break 'claimed true;
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    if checks.is_empty() {
+        quote! { false }
+    } else {
+        quote! {
+            'claimed: {
+                #(#checks)*
+                false
+            }
+        }
+    }
+}
+/// Calls each predicate with `other` normalized for the case mode.
+///
+/// Bytes must be valid UTF-8 to be passed to a predicate
+fn call_predicates(
+    paths: &[Path],
+    case_mode: CaseMode,
+    bytes: bool,
+    call: impl Fn(&Path) -> TokenStream,
+) -> Option<TokenStream> {
+    if paths.is_empty() {
+        return None;
+    }
+    let case = case_mode.runtime();
+    let calls = paths.iter().map(call);
+    let checks = quote! {
+        {
+            let other = #case.normalize(other);
+            #(#calls)||*
+        }
+    };
+    if bytes {
+        Some(quote! {
+            ::core::str::from_utf8(other).is_ok_and(|other| #checks)
+        })
+    } else {
+        Some(checks)
+    }
+}
+/// The path as written. Without generic arguments
+fn path_display(path: &Path) -> String {
+    let segments = path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>()
+        .join("::");
+    if path.leading_colon.is_some() {
+        format!("::{segments}")
+    } else {
+        segments
+    }
+}
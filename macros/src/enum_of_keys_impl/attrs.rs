@@ -1,16 +1,21 @@
+use crate::casing::Casing;
 use crate::utils::consume_comma;
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote, ToTokens, TokenStreamExt};
 use std::fmt::Debug;
 use syn::parse::{Parse, ParseStream};
-use syn::{Path, Token};
+use syn::{parenthesized, Lit, LitStr, Path, Token};
 
 mod keywords {
     use syn::custom_keyword;
     custom_keyword!(default);
+    custom_keyword!(description);
+    custom_keyword!(meta);
+    custom_keyword!(from_default);
     custom_keyword!(default_in_cow);
     custom_keyword!(impl_common_traits);
     custom_keyword!(impl_strum);
+    custom_keyword!(markers);
 }
 
 /// This attribute is used to generate an enum of keys for a struct.
@@ -30,12 +35,18 @@ pub struct EnumOfKeysAttribute {
     /// Add derive(strum::EnumIter, strum::EnumString, strum::Display, strum::EnumIs, strum::AsRefStr)
     /// to the enum of keys
     pub impl_strum: bool,
+    /// Generate a module of marker types. One for each variant
+    /// #[enum_of_keys(KeyEnumName, markers)] or #[enum_of_keys(KeyEnumName, markers = module_name)]
+    ///
+    /// The module defaults to the snake_case name of the KeyEnum
+    pub markers: Option<Ident>,
 }
 
 impl Parse for EnumOfKeysAttribute {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let name = input.parse()?;
+        let name: Path = input.parse()?;
         let mut default_in_cow = false;
+        let mut markers = None;
         let mut impl_common_traits = false;
         let mut impl_strum = false;
         consume_comma!(input);
@@ -48,9 +59,17 @@ impl Parse for EnumOfKeysAttribute {
             } else if lookahead1.peek(keywords::impl_common_traits) {
                 input.parse::<keywords::impl_common_traits>()?;
                 impl_common_traits = true;
-            }else if lookahead1.peek(keywords::impl_strum) {
+            } else if lookahead1.peek(keywords::impl_strum) {
                 input.parse::<keywords::impl_strum>()?;
                 impl_strum = true;
+            } else if lookahead1.peek(keywords::markers) {
+                input.parse::<keywords::markers>()?;
+                if input.parse::<Option<Token![=]>>()?.is_some() {
+                    markers = Some(input.parse()?);
+                } else {
+                    let key_name = &name.segments.last().expect("Key Enum Name").ident;
+                    markers = Some(format_ident!("{}", Casing::Snake.apply(&key_name.to_string())));
+                }
             } else {
                 return Err(lookahead1.error());
             }
@@ -61,6 +80,7 @@ impl Parse for EnumOfKeysAttribute {
             store_default_in_cow: default_in_cow,
             impl_common_traits,
             impl_strum,
+            markers,
         })
     }
 }
@@ -102,11 +122,23 @@ pub struct DefaultValue {
 #[derive(Debug)]
 pub struct VariantAttribute {
     pub default: Option<DefaultValue>,
+    /// Overrides the doc comment as the description of the variant
+    /// #[enum_of_keys(description = "...")]
+    pub description: Option<LitStr>,
+    /// Custom properties exposed as accessors on the KeyEnum
+    /// #[enum_of_keys(meta(status = 404, severity = "warn"))]
+    pub meta: Vec<MetaProperty>,
+    /// Build the variant with `Default::default()` fields when converting from the KeyEnum
+    /// #[enum_of_keys(from_default)]
+    pub from_default: bool,
 }
 
 impl Parse for VariantAttribute {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let mut default: Option<DefaultValue> = None;
+        let mut description: Option<LitStr> = None;
+        let mut meta: Vec<MetaProperty> = Vec::new();
+        let mut from_default = false;
         // Loop through tokens seperated by ,
 
         while !input.is_empty() {
@@ -127,11 +159,89 @@ impl Parse for VariantAttribute {
                     });
                 }
                 input.parse::<Option<Token![,]>>()?;
+            } else if peak.peek(keywords::description) {
+                input.parse::<keywords::description>()?;
+                input.parse::<Token![=]>()?;
+                description = Some(input.parse()?);
+                input.parse::<Option<Token![,]>>()?;
+            } else if peak.peek(keywords::meta) {
+                input.parse::<keywords::meta>()?;
+                let content;
+                parenthesized!(content in input);
+                let properties = content.parse_terminated(MetaProperty::parse, Token![,])?;
+                for property in properties {
+                    if meta.iter().any(|v| v.name == property.name) {
+                        return Err(syn::Error::new(
+                            property.name.span(),
+                            format!("Duplicate meta property `{}`", property.name),
+                        ));
+                    }
+                    meta.push(property);
+                }
+                input.parse::<Option<Token![,]>>()?;
+            } else if peak.peek(keywords::from_default) {
+                input.parse::<keywords::from_default>()?;
+                from_default = true;
+                input.parse::<Option<Token![,]>>()?;
             } else {
                 return Err(peak.error());
             }
         }
 
-        Ok(VariantAttribute { default })
+        Ok(VariantAttribute {
+            default,
+            description,
+            meta,
+            from_default,
+        })
+    }
+}
+
+/// A custom property of a variant.
+///
+/// # Example
+/// ```rust, ignore
+/// #[enum_of_keys(meta(status = 404, severity = "warn"))]
+/// ```
+#[derive(Debug)]
+pub struct MetaProperty {
+    pub name: Ident,
+    pub value: Lit,
+}
+impl MetaProperty {
+    /// The type returned by the accessor.
+    ///
+    /// Integer and float literals use their suffix if one is present.
+    pub fn value_type(&self) -> syn::Result<String> {
+        let value_type = match &self.value {
+            Lit::Str(_) => "&'static str",
+            Lit::ByteStr(_) => "&'static [u8]",
+            Lit::Byte(_) => "u8",
+            Lit::Char(_) => "char",
+            Lit::Bool(_) => "bool",
+            Lit::Int(value) => match value.suffix() {
+                "" => "i64",
+                suffix => suffix,
+            },
+            Lit::Float(value) => match value.suffix() {
+                "" => "f64",
+                suffix => suffix,
+            },
+            other => {
+                return Err(syn::Error::new_spanned(
+                    other,
+                    "Unsupported literal for a meta property",
+                ))
+            }
+        };
+        Ok(value_type.to_owned())
+    }
+}
+impl Parse for MetaProperty {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let value = input.parse()?;
+        Ok(MetaProperty { name, value })
     }
 }
@@ -3,7 +3,7 @@ mod variant;
 
 use crate::enum_of_keys_impl::attrs::{EnumOfKeysAttribute, InnerAttribute};
 use crate::enum_of_keys_impl::variant::Variant;
-use crate::utils::into_enum;
+use crate::utils::{into_enum, is_option, split_options};
 use proc_macro2::{Ident, TokenStream};
 use quote::{quote, TokenStreamExt};
 use syn::{Attribute, Path, Result};
@@ -62,7 +62,9 @@ pub(crate) fn expand(derive_input: DeriveInput) -> Result<TokenStream> {
         store_default_in_cow,
         impl_common_traits,
         impl_strum,
+        markers,
     } = enum_attributes;
+    let markers = markers.map(|module| expand_markers(&name, &module, &variants));
     let mut extras =
         Vec::with_capacity(impl_common_traits as usize + impl_strum as usize + inner_attrs.len());
     if impl_common_traits {
@@ -93,7 +95,10 @@ pub(crate) fn expand(derive_input: DeriveInput) -> Result<TokenStream> {
             meta: syn::parse_quote! {
                 derive(CompareToStr)
             },
-        })
+        });
+        if let Some(meta) = forward_container_compare_str(&attrs) {
+            extras.push(InnerAttribute { meta });
+        }
     }
     let inner_attrs = if extras.is_empty() {
         inner_attrs
@@ -117,20 +122,100 @@ pub(crate) fn expand(derive_input: DeriveInput) -> Result<TokenStream> {
             variants,
             get_key_lines,
             enum_name.clone(),
-        )
+        )?
     };
+    if let Some(markers) = markers {
+        result.append_all(markers);
+    }
 
     Ok(result)
 }
+/// The options of the container compare_str attribute that change which strings a variant matches.
+///
+/// Forwarded to the KeyEnum so it matches the same strings as the enum
+const FORWARDED_COMPARE_STR_OPTIONS: &[&str] = &[
+    "include_variant",
+    "rename_all",
+    "ignore_ascii_case",
+    "case_fold",
+    "to_lowercase",
+    "allow_overlap",
+];
+/// Copies the forwarded options of the container compare_str attribute to the KeyEnum. None if there are none
+fn forward_container_compare_str(attrs: &[Attribute]) -> Option<syn::Meta> {
+    let attr = attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("compare_str"))?;
+    let syn::Meta::List(list) = &attr.meta else {
+        return None;
+    };
+    let options = split_options(list.tokens.clone())
+        .into_iter()
+        .filter(|option| {
+            FORWARDED_COMPARE_STR_OPTIONS
+                .iter()
+                .any(|name| is_option(option, name))
+        })
+        .map(|option| option.into_iter().collect::<TokenStream>())
+        .collect::<Vec<_>>();
+    if options.is_empty() {
+        None
+    } else {
+        Some(syn::parse_quote! { compare_str(#(#options),*) })
+    }
+}
+fn expand_markers(og_enum: &Ident, module: &Ident, variants: &[Variant]) -> TokenStream {
+    let structs = variants.iter().map(|v| v.create_marker_struct(og_enum));
+    let impls = variants.iter().map(|v| v.create_marker_impl(og_enum, module));
+    let doc = format!("Marker types for the variants of [{og_enum}]");
+    quote! {
+        #[doc = #doc]
+        pub mod #module {
+            #(#structs)*
+        }
+        #(#impls)*
+    }
+}
 fn expand_inner(
     enum_name: TokenStream,
     og_enum: &Ident,
-    partial_eq_lines: Vec<TokenStream>,
-) -> TokenStream {
-    quote! {
+    key_enum_name: &Path,
+    variants: &[Variant],
+) -> Result<TokenStream> {
+    let partial_eq_lines = variants
+        .iter()
+        .map(|v| v.create_partial_eq_line(og_enum, key_enum_name))
+        .collect::<Vec<_>>();
+    let description_lines = variants
+        .iter()
+        .map(|v| v.create_description_line())
+        .collect::<Vec<_>>();
+    let description_entries = variants
+        .iter()
+        .map(|v| v.create_description_entry())
+        .collect::<Vec<_>>();
+    let meta_accessors = expand_meta_accessors(variants)?;
+    Ok(quote! {
+        #[automatically_derived]
+        #[allow(deprecated)]
+        impl enum_helper::KeyEnum for #enum_name{
+            fn description(&self) -> ::core::option::Option<&'static str> {
+                match self {
+                    #(#description_lines),*
+                }
+            }
+        }
         #[automatically_derived]
-        impl enum_helper::KeyEnum for #enum_name{ }
+        #[allow(deprecated)]
+        impl #enum_name {
+            /// The name and description of every variant in declaration order
+            pub const DESCRIPTIONS: &'static [(&'static str, ::core::option::Option<&'static str>)] = &[
+                #(#description_entries),*
+            ];
+            #(#meta_accessors)*
+        }
         #[automatically_derived]
+        #[allow(deprecated)]
         impl ::core::cmp::PartialEq<#enum_name> for #og_enum{
             fn eq(&self, other: &#enum_name) -> bool {
                  match (self, other) {
@@ -141,6 +226,7 @@ fn expand_inner(
             }
         }
         #[automatically_derived]
+        #[allow(deprecated)]
         impl ::core::cmp::PartialEq<#enum_name> for &'_ #og_enum{
             fn eq(&self, other: &#enum_name) -> bool {
                  match (self, other) {
@@ -152,6 +238,7 @@ fn expand_inner(
         }
 
         #[automatically_derived]
+        #[allow(deprecated)]
         impl ::core::cmp::PartialEq<#og_enum> for #enum_name{
             fn eq(&self, other: &#og_enum) -> bool {
                  match ( other,self) {
@@ -162,7 +249,7 @@ fn expand_inner(
             }
         }
 
-    }
+    })
 }
 fn expand_cow(
     name: Ident,
@@ -175,6 +262,7 @@ fn expand_cow(
     let mut to_owned_catches = Vec::new();
     for variant in variants.iter() {
         let variant_name = &variant.name;
+        let cfg_attrs = &variant.cfg_attrs;
         if variant
             .enum_of_keys_attr
             .as_ref()
@@ -182,10 +270,12 @@ fn expand_cow(
             .is_some()
         {
             to_owned_catches.push(quote! {
+                #(#cfg_attrs)*
                 #enum_name::#variant_name(v) => #enum_name::#variant_name(::std::borrow::Cow::Owned(v.as_ref().to_owned()))
             })
         } else {
             to_owned_catches.push(quote! {
+                #(#cfg_attrs)*
                 #enum_name::#variant_name => #enum_name::#variant_name
             })
         }
@@ -198,6 +288,7 @@ fn expand_cow(
         }
 
         #[automatically_derived]
+        #[allow(deprecated)]
         impl #enum_name<'_> {
             /// Creates a new copy of the Enum.
             ///
@@ -209,6 +300,7 @@ fn expand_cow(
             }
         }
         #[automatically_derived]
+        #[allow(deprecated)]
         impl enum_helper::HasKeyEnum for #name{
             type KeyEnum<'a> = #enum_name<'a> where Self: 'a;
             fn get_key(&self) -> Self::KeyEnum<'static>{
@@ -223,14 +315,40 @@ fn expand_cow(
             }
         }
     };
+    let from_key_lines = variants
+        .iter()
+        .map(|v| v.create_from_key_line(&name, &enum_name))
+        .collect::<Vec<_>>();
+    result.append_all(quote! {
+        #[automatically_derived]
+        impl<'a> ::core::convert::From<&'a #name> for #enum_name<'a> {
+            fn from(value: &'a #name) -> Self {
+                enum_helper::HasKeyEnum::get_key_borrowed(value)
+            }
+        }
+        #[automatically_derived]
+        impl ::core::convert::From<#name> for #enum_name<'static> {
+            fn from(value: #name) -> Self {
+                enum_helper::HasKeyEnum::get_key(&value)
+            }
+        }
+        #[automatically_derived]
+        #[allow(deprecated)]
+        impl<'a> ::core::convert::TryFrom<#enum_name<'a>> for #name {
+            type Error = enum_helper::FromKeyError<#enum_name<'a>>;
+            fn try_from(value: #enum_name<'a>) -> ::core::result::Result<Self, Self::Error> {
+                match value {
+                    #(#from_key_lines),*
+                }
+            }
+        }
+    });
     result.append_all(expand_inner(
         quote! { #enum_name<'_> },
         &name,
-        variants
-            .iter()
-            .map(|v| v.create_partial_eq_line(&name, &enum_name))
-            .collect::<Vec<_>>(),
-    ));
+        &enum_name,
+        &variants,
+    )?);
     Ok(result)
 }
 fn expand_no_cow(
@@ -239,7 +357,7 @@ fn expand_no_cow(
     variants: Vec<Variant>,
     get_key_lines: Vec<TokenStream>,
     enum_name: Path,
-) -> TokenStream {
+) -> Result<TokenStream> {
     let mut result = quote! {
         #[automatically_derived]
         #(#inner_attrs)*
@@ -247,6 +365,7 @@ fn expand_no_cow(
             #(#variants),*
         }
         #[automatically_derived]
+        #[allow(deprecated)]
         impl enum_helper::HasKeyEnum for #name{
             type KeyEnum<'a> = #enum_name where Self: 'static;
             fn get_key(&self) -> Self::KeyEnum<'static>{
@@ -261,13 +380,115 @@ fn expand_no_cow(
              }
         }
     };
+    let from_key_lines = variants
+        .iter()
+        .map(|v| v.create_from_key_line(&name, &enum_name))
+        .collect::<Vec<_>>();
+    result.append_all(quote! {
+        #[automatically_derived]
+        impl ::core::convert::From<&#name> for #enum_name {
+            fn from(value: &#name) -> Self {
+                enum_helper::HasKeyEnum::get_key(value)
+            }
+        }
+        #[automatically_derived]
+        impl ::core::convert::From<#name> for #enum_name {
+            fn from(value: #name) -> Self {
+                enum_helper::HasKeyEnum::get_key(&value)
+            }
+        }
+        #[automatically_derived]
+        #[allow(deprecated)]
+        impl ::core::convert::TryFrom<#enum_name> for #name {
+            type Error = enum_helper::FromKeyError<#enum_name>;
+            fn try_from(value: #enum_name) -> ::core::result::Result<Self, Self::Error> {
+                match value {
+                    #(#from_key_lines),*
+                }
+            }
+        }
+    });
     result.append_all(expand_inner(
         quote! { #enum_name },
         &name,
-        variants
-            .iter()
-            .map(|v| v.create_partial_eq_line(&name, &enum_name))
-            .collect::<Vec<_>>(),
-    ));
-    result
+        &enum_name,
+        &variants,
+    )?);
+    Ok(result)
+}
+/// Names of the items generated on the KeyEnum. A meta accessor with one of these names would collide with or shadow it.
+///
+/// Includes the items of `CompareToStr`. The KeyEnum derives it when a variant has a compare_str attribute
+const RESERVED_META_NAMES: &[&str] = &[
+    "description",
+    "DESCRIPTIONS",
+    "to_owned",
+    "equals_str",
+    "match_details",
+    "aliases",
+    "substrings",
+    "suggest",
+    "PATTERNS",
+    "match_str",
+    "match_key",
+    "equals_bytes",
+    "equals_os_str",
+    "equals_path",
+    "equals_type",
+    "equals_str_const",
+    "match_str_const",
+];
+/// Generates an accessor for every meta property
+///
+/// Every variant must use the same literal type for a property
+fn expand_meta_accessors(variants: &[Variant]) -> Result<Vec<TokenStream>> {
+    let mut properties: Vec<(&Ident, String)> = Vec::new();
+    for variant in variants {
+        for property in variant.meta() {
+            if RESERVED_META_NAMES
+                .iter()
+                .any(|reserved| property.name == reserved)
+            {
+                return Err(Error::new(
+                    property.name.span(),
+                    format!(
+                        "`{}` is already generated on the KeyEnum. Use another name for the meta property",
+                        property.name
+                    ),
+                ));
+            }
+            let value_type = property.value_type()?;
+            match properties.iter().find(|(name, _)| *name == &property.name) {
+                Some((_, existing)) => {
+                    if existing != &value_type {
+                        return Err(Error::new_spanned(
+                            &property.value,
+                            format!(
+                                "Meta property `{}` is `{}` on another variant but `{}` here",
+                                property.name, existing, value_type
+                            ),
+                        ));
+                    }
+                }
+                None => properties.push((&property.name, value_type)),
+            }
+        }
+    }
+    let accessors = properties
+        .into_iter()
+        .map(|(name, value_type)| {
+            let value_type: syn::Type = syn::parse_str(&value_type)?;
+            let lines = variants.iter().map(|v| v.create_meta_line(name));
+            let doc = format!("Returns the `{name}` meta property of the variant");
+            Ok(quote! {
+                #[doc = #doc]
+                pub const fn #name(&self) -> ::core::option::Option<#value_type> {
+                    match self {
+                        #(#lines),*
+                    }
+                }
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(accessors)
 }
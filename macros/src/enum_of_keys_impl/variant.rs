@@ -1,7 +1,8 @@
-use crate::enum_of_keys_impl::attrs::{InnerAttribute, VariantAttribute};
+use crate::enum_of_keys_impl::attrs::{InnerAttribute, MetaProperty, VariantAttribute};
 use crate::enum_of_keys_impl::find_and_parse_inner_attrs;
+use crate::utils::{doc_comment, is_option, split_options};
 use proc_macro2::{Ident, TokenStream};
-use quote::{quote, ToTokens, TokenStreamExt};
+use quote::{format_ident, quote, ToTokens, TokenStreamExt};
 use syn::spanned::Spanned;
 use syn::Error;
 use syn::{Fields, Path, Result};
@@ -14,6 +15,11 @@ pub struct Variant {
     pub default_in_cow: bool,
     pub fields_collection: TokenStream,
     pub has_compare_str: bool,
+    /// `cfg` and `cfg_attr` attributes that must also be placed on every generated match arm
+    pub cfg_attrs: Vec<InnerAttribute>,
+    /// The `description` option or the doc comment of the variant
+    pub description: Option<String>,
+    pub fields: Fields,
 }
 
 impl Variant {
@@ -26,14 +32,34 @@ impl Variant {
             .transpose()?;
         let mut has_compare_str = false;
         let mut inner_attrs = find_and_parse_inner_attrs(&variant.attrs)?;
+        let mut cfg_attrs = Vec::new();
+        for attr in &variant.attrs {
+            let path = attr.path();
+            if path.is_ident("cfg") || path.is_ident("cfg_attr") {
+                cfg_attrs.push(InnerAttribute {
+                    meta: attr.meta.clone(),
+                });
+                inner_attrs.push(InnerAttribute {
+                    meta: attr.meta.clone(),
+                });
+            } else if path.is_ident("deprecated") || path.is_ident("doc") {
+                inner_attrs.push(InnerAttribute {
+                    meta: attr.meta.clone(),
+                });
+            }
+        }
         if let Some(value) = variant
             .attrs
             .iter()
             .find(|v| v.path().is_ident("compare_str"))
         {
             has_compare_str = true;
+            let is_default = attributes
+                .as_ref()
+                .and_then(|v| v.default.as_ref())
+                .is_some();
             inner_attrs.push(InnerAttribute {
-                meta: value.meta.clone(),
+                meta: forward_compare_str(&value.meta, is_default)?,
             })
         }
         if let Some(default_value) = attributes.as_mut().and_then(|v| v.default.as_mut()) {
@@ -72,7 +98,12 @@ impl Variant {
                 }
             }
         }
-        let fields_collection = match variant.fields {
+        let description = attributes
+            .as_ref()
+            .and_then(|v| v.description.as_ref())
+            .map(|v| v.value())
+            .or_else(|| doc_comment(&variant.attrs));
+        let fields_collection = match &variant.fields {
             Fields::Named(_) => {
                 quote! {
                     { .. }
@@ -95,6 +126,9 @@ impl Variant {
             default_in_cow,
             fields_collection,
             has_compare_str,
+            cfg_attrs,
+            description,
+            fields: variant.fields,
         })
     }
     pub fn has_default(&self) -> bool {
@@ -103,6 +137,218 @@ impl Variant {
             .and_then(|v| v.default.as_ref())
             .is_some()
     }
+    /// The pattern to match this variant on the KeyEnum
+    pub fn key_pattern(&self) -> TokenStream {
+        let name = &self.name;
+        if self.has_default() {
+            quote! { Self::#name(..) }
+        } else {
+            quote! { Self::#name }
+        }
+    }
+    pub fn meta(&self) -> &[MetaProperty] {
+        self.enum_of_keys_attr
+            .as_ref()
+            .map(|v| v.meta.as_slice())
+            .unwrap_or_default()
+    }
+    pub fn create_meta_line(&self, property: &Ident) -> TokenStream {
+        let cfg_attrs = &self.cfg_attrs;
+        let pattern = self.key_pattern();
+        let value = match self.meta().iter().find(|v| &v.name == property) {
+            Some(property) => {
+                let value = &property.value;
+                quote! { ::core::option::Option::Some(#value) }
+            }
+            None => quote! { ::core::option::Option::None },
+        };
+        quote! {
+            #(#cfg_attrs)*
+            #pattern => #value
+        }
+    }
+    pub fn create_description_line(&self) -> TokenStream {
+        let Self {
+            description,
+            cfg_attrs,
+            ..
+        } = self;
+        let pattern = self.key_pattern();
+        let description = option_str(description.as_deref());
+        quote! {
+            #(#cfg_attrs)*
+            #pattern => #description
+        }
+    }
+    /// An entry of the `DESCRIPTIONS` table
+    pub fn create_description_entry(&self) -> TokenStream {
+        let Self {
+            name,
+            description,
+            cfg_attrs,
+            ..
+        } = self;
+        let name = name.to_string();
+        let description = option_str(description.as_deref());
+        quote! {
+            #(#cfg_attrs)*
+            (#name, #description)
+        }
+    }
+    /// Creates the match arm converting the KeyEnum back into the original enum.
+    ///
+    /// Variants that can not be built from the key return a [FromKeyError](enum_helper::FromKeyError)
+    pub fn create_from_key_line(&self, enum_name: &Ident, key_enum_name: &Path) -> TokenStream {
+        let Self {
+            name, cfg_attrs, ..
+        } = self;
+        let from_default = self
+            .enum_of_keys_attr
+            .as_ref()
+            .map(|v| v.from_default)
+            .unwrap_or_default();
+        let default_value = self
+            .enum_of_keys_attr
+            .as_ref()
+            .and_then(|v| v.default.as_ref());
+        let key_value = if self.default_in_cow {
+            quote! { ::core::convert::Into::into(value.into_owned()) }
+        } else {
+            quote! { ::core::convert::Into::into(value) }
+        };
+        let default_field = quote! { ::core::default::Default::default() };
+        let (key_pattern, key_fields) = if default_value.is_some() {
+            (quote! { #key_enum_name::#name(value) }, quote! { (..) })
+        } else {
+            (quote! { #key_enum_name::#name }, quote! {})
+        };
+        let value = match &self.fields {
+            Fields::Unit => Some(quote! { #enum_name::#name }),
+            Fields::Named(named) => named
+                .named
+                .iter()
+                .map(|field| {
+                    let field_name = field.ident.as_ref().expect("Named Field");
+                    if default_value.is_some_and(|v| &v.key_name == field_name) {
+                        Some(quote! { #field_name: #key_value })
+                    } else if from_default {
+                        Some(quote! { #field_name: #default_field })
+                    } else {
+                        None
+                    }
+                })
+                .collect::<Option<Vec<_>>>()
+                .map(|fields| quote! { #enum_name::#name { #(#fields),* } }),
+            Fields::Unnamed(unnamed) => (0..unnamed.unnamed.len())
+                .map(|index| {
+                    if index == 0 && default_value.is_some() {
+                        Some(key_value.clone())
+                    } else if from_default {
+                        Some(default_field.clone())
+                    } else {
+                        None
+                    }
+                })
+                .collect::<Option<Vec<_>>>()
+                .map(|fields| quote! { #enum_name::#name ( #(#fields),* ) }),
+        };
+        match value {
+            Some(value) => quote! {
+                #(#cfg_attrs)*
+                #key_pattern => ::core::result::Result::Ok(#value)
+            },
+            None => quote! {
+                #(#cfg_attrs)*
+                key @ #key_enum_name::#name #key_fields => ::core::result::Result::Err(enum_helper::FromKeyError::new(key))
+            },
+        }
+    }
+    /// Creates the marker type for the variant. Placed inside the markers module
+    pub fn create_marker_struct(&self, enum_name: &Ident) -> TokenStream {
+        let Self {
+            name, cfg_attrs, ..
+        } = self;
+        let doc = format!("Marker for [`{enum_name}::{name}`](super::{enum_name}::{name})");
+        quote! {
+            #(#cfg_attrs)*
+            #[doc = #doc]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+            pub struct #name;
+        }
+    }
+    /// Implements [VariantMarker](enum_helper::VariantMarker) for the marker type of the variant
+    pub fn create_marker_impl(&self, enum_name: &Ident, module: &Ident) -> TokenStream {
+        let Self {
+            name, cfg_attrs, ..
+        } = self;
+        let (bindings, types): (Vec<Ident>, Vec<&syn::Type>) = match &self.fields {
+            Fields::Named(named) => named
+                .named
+                .iter()
+                .map(|field| (field.ident.clone().expect("Named Field"), &field.ty))
+                .unzip(),
+            Fields::Unnamed(unnamed) => unnamed
+                .unnamed
+                .iter()
+                .enumerate()
+                .map(|(index, field)| (format_ident!("field_{}", index), &field.ty))
+                .unzip(),
+            Fields::Unit => (Vec::new(), Vec::new()),
+        };
+        let pattern = match &self.fields {
+            Fields::Named(_) => quote! { #enum_name::#name { #(#bindings),* } },
+            Fields::Unnamed(_) => quote! { #enum_name::#name ( #(#bindings),* ) },
+            Fields::Unit => quote! { #enum_name::#name },
+        };
+        let (payload, payload_ref, payload_mut, payload_value) = if bindings.len() == 1 {
+            let ty = types[0];
+            let binding = &bindings[0];
+            (
+                quote! { #ty },
+                quote! { &'a #ty },
+                quote! { &'a mut #ty },
+                quote! { #binding },
+            )
+        } else {
+            (
+                quote! { ( #(#types),* ) },
+                quote! { ( #(&'a #types),* ) },
+                quote! { ( #(&'a mut #types),* ) },
+                quote! { ( #(#bindings),* ) },
+            )
+        };
+        quote! {
+            #(#cfg_attrs)*
+            #[automatically_derived]
+            #[allow(deprecated, unreachable_patterns)]
+            impl enum_helper::VariantMarker<#enum_name> for #module::#name {
+                type Payload = #payload;
+                type PayloadRef<'a> = #payload_ref where #enum_name: 'a;
+                type PayloadMut<'a> = #payload_mut where #enum_name: 'a;
+
+                fn extract(value: &#enum_name) -> ::core::option::Option<Self::PayloadRef<'_>> {
+                    match value {
+                        #pattern => ::core::option::Option::Some(#payload_value),
+                        _ => ::core::option::Option::None,
+                    }
+                }
+
+                fn extract_mut(value: &mut #enum_name) -> ::core::option::Option<Self::PayloadMut<'_>> {
+                    match value {
+                        #pattern => ::core::option::Option::Some(#payload_value),
+                        _ => ::core::option::Option::None,
+                    }
+                }
+
+                fn into_payload(value: #enum_name) -> ::core::result::Result<Self::Payload, #enum_name> {
+                    match value {
+                        #pattern => ::core::result::Result::Ok(#payload_value),
+                        value => ::core::result::Result::Err(value),
+                    }
+                }
+            }
+        }
+    }
     pub fn create_get_key_line_owned(
         &self,
         enum_name: &Ident,
@@ -112,6 +358,7 @@ impl Variant {
             name,
             fields_collection,
             enum_of_keys_attr,
+            cfg_attrs,
             ..
         } = self;
         if let Some(default_value) = enum_of_keys_attr.as_ref().and_then(|v| v.default.as_ref()) {
@@ -121,10 +368,12 @@ impl Variant {
                 .expect("unwrap_variant");
             let key_name = &default_value.key_name;
             quote! {
+                #(#cfg_attrs)*
                 #enum_name::#name #unwrap_variant  => #key_enum_name::#name(::std::borrow::Cow::Owned(#key_name.to_owned()))
             }
         } else {
             quote! {
+                #(#cfg_attrs)*
                 #enum_name::#name #fields_collection => #key_enum_name::#name
             }
         }
@@ -134,6 +383,7 @@ impl Variant {
             name,
             fields_collection,
             enum_of_keys_attr,
+            cfg_attrs,
             ..
         } = self;
         if let Some(default_value) = enum_of_keys_attr.as_ref().and_then(|v| v.default.as_ref()) {
@@ -144,15 +394,18 @@ impl Variant {
             let key_name = &default_value.key_name;
             if self.default_in_cow {
                 quote! {
+                    #(#cfg_attrs)*
                     #enum_name::#name #unwrap_variant => #key_enum_name::#name(::std::borrow::Cow::Borrowed(&#key_name))
                 }
             } else {
                 quote! {
+                    #(#cfg_attrs)*
                     #enum_name::#name #unwrap_variant => #key_enum_name::#name(#key_name.clone())
                 }
             }
         } else {
             quote! {
+                #(#cfg_attrs)*
                 #enum_name::#name #fields_collection => #key_enum_name::#name
             }
         }
@@ -163,6 +416,7 @@ impl Variant {
             name,
             enum_of_keys_attr,
             fields_collection,
+            cfg_attrs,
             ..
         } = self;
         if let Some(default_value) = enum_of_keys_attr.as_ref().and_then(|v| v.default.as_ref()) {
@@ -172,15 +426,45 @@ impl Variant {
                 .expect("unwrap_variant");
             let key_name = &default_value.key_name;
             quote! {
+                #(#cfg_attrs)*
                 (#enum_name::#name #unwrap_variant, #key_enum_name::#name(b)) => #key_name == b
             }
         } else {
             quote! {
+                #(#cfg_attrs)*
                 (#enum_name::#name #fields_collection, #key_enum_name::#name) => true
             }
         }
     }
 }
+/// Rewrites the `field` and `with_self` options of a compare_str attribute for the KeyEnum.
+///
+/// The key of the default variant only holds the string. Other keys have no fields.
+/// `with_self` is dropped. Its function takes the original enum, not the KeyEnum
+fn forward_compare_str(meta: &syn::Meta, is_default: bool) -> Result<syn::Meta> {
+    let syn::Meta::List(list) = meta else {
+        return Ok(meta.clone());
+    };
+    let options = split_options(list.tokens.clone())
+        .into_iter()
+        .filter_map(|option| {
+            if is_option(&option, "with_self") {
+                return None;
+            }
+            match (is_option(&option, "field"), is_default) {
+                (true, true) => Some(quote!(field)),
+                (true, false) => None,
+                (false, _) => Some(option.into_iter().collect::<TokenStream>()),
+            }
+        });
+    Ok(syn::parse_quote! { compare_str(#(#options),*) })
+}
+fn option_str(value: Option<&str>) -> TokenStream {
+    match value {
+        Some(value) => quote! { ::core::option::Option::Some(#value) },
+        None => quote! { ::core::option::Option::None },
+    }
+}
 impl ToTokens for Variant {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let Variant {
@@ -1,3 +1,4 @@
+pub(crate) mod casing;
 mod compare_str;
 mod enum_of_keys_impl;
 pub(crate) mod utils;
@@ -12,10 +13,22 @@ use syn::{parse_macro_input, DeriveInput};
 ///    - Options:
 ///         - First Value is the name of the KeyEnum (Required)
 ///         - `default_in_cow` Will put the default Variant in a Cow
+///         - `markers` or `markers = module_name` Generates a module of marker types implementing `VariantMarker`. One for each variant.
+///           The module defaults to the snake_case name of the KeyEnum
 /// - `#[enum_attr(...)]` - This is used to specify any attributes that should be added to the KeyEnum
 /// - `#[enum_of_keys(..)]` On a variant to specify any options for the variant.
 ///    - Options:
 ///         - `default` - This is used to specify the default variant.
+///         - `description = "..."` - The description returned by `KeyEnum::description`. Defaults to the doc comment of the variant.
+///         - `meta(status = 404, severity = "warn")` - Custom properties. Each property becomes a `const fn status(&self) -> Option<i64>` accessor on the KeyEnum.
+///           A property must use the same literal type on every variant. Integer and float literals default to `i64` and `f64` unless suffixed.
+///           Names of items already generated on the KeyEnum, like `description` or `to_owned`, are a compile error.
+///         - `from_default` - Fields not carried by the key are filled with `Default::default()` when converting from the KeyEnum.
+/// - `#[cfg(..)]`, `#[cfg_attr(..)]`, `#[deprecated]` and doc comments on a variant are copied to the key variant.
+///   `cfg` and `cfg_attr` are also added to every generated match arm.
+/// - `#[compare_str(..)]` on a variant makes the KeyEnum derive `CompareToStr` with the same patterns.
+///   `rename_all`, `include_variant`, `allow_overlap` and the case mode of the container `#[compare_str(..)]` are copied to it.
+///   So both match the same strings and report the same overlaps.
 ///
 /// ```rust, ignore
 /// use enum_helpers_macros::EnumOfKeys;
@@ -49,6 +62,9 @@ use syn::{parse_macro_input, DeriveInput};
 /// Generates the following. Trait Impls Too
 ///  - [HasEnumKey](enum_of_keys_impl::HasEnumKey)
 ///  - [PartialEq] for KeyEnum and OriginalEnum
+///  - [From] `&OriginalEnum` and `OriginalEnum` for KeyEnum
+///  - [TryFrom] KeyEnum for OriginalEnum. Unit variants, the default variant and `from_default` variants are built. Other variants return a `FromKeyError`
+///  - `KeyEnum::description` and a `DESCRIPTIONS` const on the KeyEnum listing the name and description of each variant
 #[proc_macro_derive(EnumOfKeys, attributes(enum_of_keys, enum_attr, compare_str))]
 pub fn enum_of_keys(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
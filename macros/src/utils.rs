@@ -21,3 +21,52 @@ macro_rules! consume_comma {
     };
 }
 pub(crate) use consume_comma;
+
+/// Joins the doc comments (`///` or `#[doc = "..."]`) of an item into a single string.
+///
+/// Returns `None` if there are no doc comments
+pub(crate) fn doc_comment(attrs: &[syn::Attribute]) -> Option<String> {
+    let mut lines = Vec::new();
+    for attr in attrs {
+        if !attr.path().is_ident("doc") {
+            continue;
+        }
+        if let syn::Meta::NameValue(syn::MetaNameValue {
+            value:
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(value),
+                    ..
+                }),
+            ..
+        }) = &attr.meta
+        {
+            let value = value.value();
+            lines.push(value.strip_prefix(' ').unwrap_or(&value).trim_end().to_owned());
+        }
+    }
+    let doc = lines.join("\n");
+    let doc = doc.trim();
+    if doc.is_empty() {
+        None
+    } else {
+        Some(doc.to_owned())
+    }
+}
+/// Splits the tokens of an attribute into its comma separated options. Empty options are skipped
+pub(crate) fn split_options(tokens: proc_macro2::TokenStream) -> Vec<Vec<proc_macro2::TokenTree>> {
+    let mut options = vec![Vec::new()];
+    for token in tokens {
+        match &token {
+            proc_macro2::TokenTree::Punct(punct) if punct.as_char() == ',' => {
+                options.push(Vec::new())
+            }
+            _ => options.last_mut().expect("At least one option").push(token),
+        }
+    }
+    options.retain(|option| !option.is_empty());
+    options
+}
+/// Checks if an option split by [split_options] starts with `name`
+pub(crate) fn is_option(option: &[proc_macro2::TokenTree], name: &str) -> bool {
+    matches!(option.first(), Some(proc_macro2::TokenTree::Ident(ident)) if ident == name)
+}
@@ -0,0 +1,642 @@
+//! Types and functions used by the code generated by `CompareToStr`.
+//!
+//! Patterns are normalized when the macro expands. So the pattern argument is expected to already be in the normalized form of the [CaseMode].
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::mem::discriminant;
+use std::ops::Range;
+#[cfg(feature = "syn")]
+pub use syn;
+
+/// How the case of a string is handled when compared to a pattern.
+///
+/// None of the comparisons allocate. [CaseMode::normalize] only allocates if the string is not already normalized
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CaseMode {
+    /// Exact comparison
+    #[default]
+    Sensitive,
+    /// Ignores ASCII case. Patterns are ASCII lowercase
+    AsciiCaseInsensitive,
+    /// Compares the Unicode full case folding of the string. See [fold_char]. Patterns are case folded
+    CaseFold,
+}
+impl CaseMode {
+    /// Checks if `value` equals `pattern`
+    pub fn eq(self, value: &str, pattern: &str) -> bool {
+        match self {
+            CaseMode::Sensitive => value == pattern,
+            CaseMode::AsciiCaseInsensitive => value.eq_ignore_ascii_case(pattern),
+            CaseMode::CaseFold => value.chars().flat_map(fold_char).eq(pattern.chars()),
+        }
+    }
+    /// Checks if two strings are equal. Neither string is expected to be normalized
+    pub fn eq_unnormalized(self, value: &str, other: &str) -> bool {
+        match self {
+            CaseMode::CaseFold => value
+                .chars()
+                .flat_map(fold_char)
+                .eq(other.chars().flat_map(fold_char)),
+            _ => self.eq(value, other),
+        }
+    }
+    /// Returns the length in bytes of the prefix of `value` matching `pattern`
+    ///
+    /// With `case_fold` the folded characters are compared. So `s` is a prefix of `ßa`.
+    /// A pattern ending inside a character that folds to several characters includes the whole character
+    pub fn prefix_len(self, value: &str, pattern: &str) -> Option<usize> {
+        match self {
+            CaseMode::Sensitive => value.starts_with(pattern).then_some(pattern.len()),
+            CaseMode::AsciiCaseInsensitive => value
+                .as_bytes()
+                .get(..pattern.len())
+                .is_some_and(|prefix| prefix.eq_ignore_ascii_case(pattern.as_bytes()))
+                .then_some(pattern.len()),
+            CaseMode::CaseFold => fold_prefix_len(value, pattern, 0),
+        }
+    }
+    /// Returns the length in bytes of the suffix of `value` matching `pattern`
+    ///
+    /// Like [Self::prefix_len] a pattern starting inside a character that folds to several characters includes it
+    pub fn suffix_len(self, value: &str, pattern: &str) -> Option<usize> {
+        match self {
+            CaseMode::Sensitive => value.ends_with(pattern).then_some(pattern.len()),
+            CaseMode::AsciiCaseInsensitive => value
+                .len()
+                .checked_sub(pattern.len())
+                .and_then(|start| value.as_bytes().get(start..))
+                .is_some_and(|suffix| suffix.eq_ignore_ascii_case(pattern.as_bytes()))
+                .then_some(pattern.len()),
+            CaseMode::CaseFold => {
+                let mut pattern = pattern.chars().rev().peekable();
+                if pattern.peek().is_none() {
+                    return Some(0);
+                }
+                for (index, c) in value.char_indices().rev() {
+                    for folded in fold_char(c).rev() {
+                        match pattern.next() {
+                            Some(expected) if expected == folded => {}
+                            Some(_) => return None,
+                            None => return Some(value.len() - index),
+                        }
+                    }
+                    if pattern.peek().is_none() {
+                        return Some(value.len() - index);
+                    }
+                }
+                None
+            }
+        }
+    }
+    pub fn starts_with(self, value: &str, pattern: &str) -> bool {
+        self.prefix_len(value, pattern).is_some()
+    }
+    pub fn ends_with(self, value: &str, pattern: &str) -> bool {
+        self.suffix_len(value, pattern).is_some()
+    }
+    /// Returns the byte range of every match of `pattern` in `value`
+    ///
+    /// With `case_fold` a match can start inside a character that folds to several characters. `se` is found in `maße`
+    pub fn match_indices<'a>(
+        self,
+        value: &'a str,
+        pattern: &'a str,
+    ) -> impl Iterator<Item = Range<usize>> + 'a {
+        value
+            .char_indices()
+            .map(|(index, _)| index)
+            .chain(std::iter::once(value.len()))
+            .filter_map(move |start| {
+                let rest = &value[start..];
+                let len = match (self, rest.chars().next()) {
+                    (CaseMode::CaseFold, Some(c)) => (0..fold_char(c).count())
+                        .find_map(|skip| fold_prefix_len(rest, pattern, skip)),
+                    _ => self.prefix_len(rest, pattern),
+                };
+                len.map(|len| start..start + len)
+            })
+    }
+    /// Returns the byte range of the first match of `pattern` in `value`
+    pub fn find(self, value: &str, pattern: &str) -> Option<Range<usize>> {
+        match self {
+            CaseMode::Sensitive => value
+                .find(pattern)
+                .map(|start| start..start + pattern.len()),
+            _ => self.match_indices(value, pattern).next(),
+        }
+    }
+    pub fn contains(self, value: &str, pattern: &str) -> bool {
+        match self {
+            CaseMode::Sensitive => value.contains(pattern),
+            CaseMode::AsciiCaseInsensitive => {
+                pattern.is_empty()
+                    || value
+                        .as_bytes()
+                        .windows(pattern.len())
+                        .any(|window| window.eq_ignore_ascii_case(pattern.as_bytes()))
+            }
+            CaseMode::CaseFold => self.find(value, pattern).is_some(),
+        }
+    }
+    /// Returns the byte range of the first match of `pattern` in `value` that is a whole word.
+    ///
+    /// A word is surrounded by the start or end of the string or a character that is not alphanumeric or `_`
+    pub fn find_word(self, value: &str, pattern: &str) -> Option<Range<usize>> {
+        self.match_indices(value, pattern).find(|range| {
+            let before = value[..range.start].chars().next_back();
+            let after = value[range.end..].chars().next();
+            !before.is_some_and(is_word_char) && !after.is_some_and(is_word_char)
+        })
+    }
+    pub fn contains_word(self, value: &str, pattern: &str) -> bool {
+        self.find_word(value, pattern).is_some()
+    }
+    /// Returns the byte range of the trailing path segments of `value` matching `pattern`.
+    ///
+    /// `Uuid` and `uuid::Uuid` both match `uuid::Uuid` but `Uuid` does not match `MyUuid`
+    pub fn find_path_suffix(self, value: &str, pattern: &str) -> Option<Range<usize>> {
+        let start = value.len() - self.suffix_len(value, pattern)?;
+        let prefix = &value[..start];
+        (prefix.is_empty() || prefix.ends_with("::")).then_some(start..value.len())
+    }
+    pub fn ends_with_path(self, value: &str, pattern: &str) -> bool {
+        self.find_path_suffix(value, pattern).is_some()
+    }
+    /// Checks if the bytes equal `pattern`.
+    ///
+    /// `case_fold` only matches valid UTF-8
+    pub fn eq_bytes(self, value: &[u8], pattern: &str) -> bool {
+        match self {
+            CaseMode::Sensitive => value == pattern.as_bytes(),
+            CaseMode::AsciiCaseInsensitive => value.eq_ignore_ascii_case(pattern.as_bytes()),
+            CaseMode::CaseFold => {
+                std::str::from_utf8(value).is_ok_and(|value| self.eq(value, pattern))
+            }
+        }
+    }
+    /// Checks if the bytes equal a string that is not normalized
+    pub fn eq_unnormalized_bytes(self, value: &[u8], other: &str) -> bool {
+        match self {
+            CaseMode::CaseFold => {
+                std::str::from_utf8(value).is_ok_and(|value| self.eq_unnormalized(value, other))
+            }
+            _ => self.eq_bytes(value, other),
+        }
+    }
+    pub fn starts_with_bytes(self, value: &[u8], pattern: &str) -> bool {
+        match self {
+            CaseMode::CaseFold => {
+                std::str::from_utf8(value).is_ok_and(|value| self.starts_with(value, pattern))
+            }
+            _ => value
+                .get(..pattern.len())
+                .is_some_and(|prefix| self.eq_bytes(prefix, pattern)),
+        }
+    }
+    pub fn ends_with_bytes(self, value: &[u8], pattern: &str) -> bool {
+        match self {
+            CaseMode::CaseFold => {
+                std::str::from_utf8(value).is_ok_and(|value| self.ends_with(value, pattern))
+            }
+            _ => value
+                .len()
+                .checked_sub(pattern.len())
+                .is_some_and(|start| self.eq_bytes(&value[start..], pattern)),
+        }
+    }
+    pub fn contains_bytes(self, value: &[u8], pattern: &str) -> bool {
+        match self {
+            CaseMode::CaseFold => {
+                std::str::from_utf8(value).is_ok_and(|value| self.contains(value, pattern))
+            }
+            _ => {
+                pattern.is_empty()
+                    || value
+                        .windows(pattern.len())
+                        .any(|window| self.eq_bytes(window, pattern))
+            }
+        }
+    }
+    /// The bytes version of [Self::ends_with_path]
+    pub fn ends_with_path_bytes(self, value: &[u8], pattern: &str) -> bool {
+        match self {
+            CaseMode::CaseFold => {
+                std::str::from_utf8(value).is_ok_and(|value| self.ends_with_path(value, pattern))
+            }
+            _ => {
+                self.ends_with_bytes(value, pattern) && {
+                    let prefix = &value[..value.len() - pattern.len()];
+                    prefix.is_empty() || prefix.ends_with(b"::")
+                }
+            }
+        }
+    }
+    /// Normalizes a string for this mode. Only allocates if the string is not already normalized.
+    ///
+    /// Used to pass the string to `with` predicates
+    pub fn normalize(self, value: &str) -> Cow<'_, str> {
+        match self {
+            CaseMode::Sensitive => Cow::Borrowed(value),
+            CaseMode::AsciiCaseInsensitive if value.bytes().any(|b| b.is_ascii_uppercase()) => {
+                Cow::Owned(value.to_ascii_lowercase())
+            }
+            CaseMode::AsciiCaseInsensitive => Cow::Borrowed(value),
+            CaseMode::CaseFold if value.chars().any(|c| fold_char(c).ne([c])) => {
+                Cow::Owned(value.chars().flat_map(fold_char).collect())
+            }
+            CaseMode::CaseFold => Cow::Borrowed(value),
+        }
+    }
+    /// The number of single character insertions, deletions or substitutions turning `a` into `b`.
+    ///
+    /// Characters are compared with [Self::normalize_char].
+    /// Only allocates if `b` has more than [EDIT_DISTANCE_STACK_LEN] characters
+    pub fn edit_distance(self, a: &str, b: &str) -> usize {
+        let len = b.chars().count();
+        let mut stack = [[0; EDIT_DISTANCE_STACK_LEN + 1]; 2];
+        let mut heap;
+        let (mut previous, mut current) = if len <= EDIT_DISTANCE_STACK_LEN {
+            let [previous, current] = &mut stack;
+            (&mut previous[..=len], &mut current[..=len])
+        } else {
+            heap = vec![0; 2 * (len + 1)];
+            heap.split_at_mut(len + 1)
+        };
+        for (j, distance) in previous.iter_mut().enumerate() {
+            *distance = j;
+        }
+        for (i, a) in a.chars().map(|c| self.normalize_char(c)).enumerate() {
+            current[0] = i + 1;
+            for (j, b) in b.chars().map(|c| self.normalize_char(c)).enumerate() {
+                let substitution = previous[j] + usize::from(a != b);
+                current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+            }
+            std::mem::swap(&mut previous, &mut current);
+        }
+        previous[len]
+    }
+    /// Returns the index of the candidate closest to `value`. If it is at most `max_distance` edits away.
+    ///
+    /// Ties are broken by the order of the candidates
+    pub fn closest(self, value: &str, candidates: &[&str], max_distance: usize) -> Option<usize> {
+        candidates
+            .iter()
+            .enumerate()
+            // Strings whose lengths differ by more than the max distance can never be close enough
+            .filter(|(_, candidate)| {
+                value.chars().count().abs_diff(candidate.chars().count()) <= max_distance
+            })
+            .map(|(index, candidate)| (index, self.edit_distance(value, candidate)))
+            .filter(|(_, distance)| *distance <= max_distance)
+            .min_by_key(|(_, distance)| *distance)
+            .map(|(index, _)| index)
+    }
+    /// Returns the candidate most likely meant by `value`.
+    ///
+    /// A candidate can be one edit away for every three characters. At least one edit is allowed
+    pub fn suggest(self, value: &str, candidates: &[&'static str]) -> Option<&'static str> {
+        let max_distance = (value.chars().count() / 3).max(1);
+        self.closest(value, candidates, max_distance)
+            .map(|index| candidates[index])
+    }
+    /// Normalizes a single character. Characters that fold to multiple characters are returned unchanged
+    pub fn normalize_char(self, c: char) -> char {
+        match self {
+            CaseMode::Sensitive => c,
+            CaseMode::AsciiCaseInsensitive => c.to_ascii_lowercase(),
+            CaseMode::CaseFold => {
+                let mut folded = fold_char(c);
+                match (folded.next(), folded.next()) {
+                    (Some(folded), None) => folded,
+                    _ => c,
+                }
+            }
+        }
+    }
+}
+/// An Aho-Corasick automaton generated by `CompareToStr` for enums with many `contains` patterns.
+///
+/// Bytes are mapped to classes. Then each state has a transition for each class
+#[derive(Debug)]
+pub struct MultiPattern {
+    pub classes: [u8; 256],
+    pub num_classes: usize,
+    pub transitions: &'static [u32],
+    /// The lowest pattern index found when reaching each state. `u32::MAX` if none
+    pub outputs: &'static [u32],
+}
+impl MultiPattern {
+    /// Scans the string once and returns the lowest index of all patterns it contains. `u32::MAX` if none
+    pub fn lowest_match(&self, value: &str, case_mode: CaseMode) -> u32 {
+        let mut state = 0usize;
+        let mut lowest = self.outputs[0];
+        for mut byte in value.bytes() {
+            if case_mode == CaseMode::AsciiCaseInsensitive {
+                byte = byte.to_ascii_lowercase();
+            }
+            let class = self.classes[byte as usize] as usize;
+            state = self.transitions[state * self.num_classes + class] as usize;
+            lowest = lowest.min(self.outputs[state]);
+            if lowest == 0 {
+                break;
+            }
+        }
+        lowest
+    }
+}
+/// The kind of check that matched a string. Returned in [StrMatch]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MatchKind {
+    /// `equals[...]`
+    Equals,
+    /// The name of the variant. Added by `include_variant`
+    VariantName,
+    /// `contains[...]`
+    Contains,
+    /// `starts_with[...]`
+    StartsWith,
+    /// `ends_with[...]`
+    EndsWith,
+    /// `word[...]`
+    Word,
+    /// `path_suffix[...]`
+    PathSuffix,
+    /// `glob[...]`
+    Glob,
+    /// `field`. The pattern is the value of the field
+    Field,
+    /// `with`. The pattern is the path of the function
+    Predicate,
+    /// `similar`. The string is a few edits away from the pattern
+    Similar,
+    /// The `fallback` variant. No other variant matched. The pattern is empty
+    Fallback,
+}
+/// Describes which pattern of a variant matched a string.
+///
+/// Returned by the `match_details` function generated by `CompareToStr`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StrMatch<'a> {
+    pub kind: MatchKind,
+    /// The pattern as written in the attribute
+    pub pattern: &'a str,
+    /// The byte range of the string that matched the pattern
+    pub range: Range<usize>,
+}
+/// The compile time patterns of an enum. Implemented by `CompareToStr`
+pub trait StrPatterns: Sized {
+    /// The case mode the patterns are compared with
+    const CASE_MODE: CaseMode;
+    /// Checks if the value matches the string
+    fn equals_str(&self, other: &str) -> bool;
+    /// Finds the unit variant matching the string
+    fn match_str(other: &str) -> Option<Self>;
+    /// The `equals` patterns of the variant as written
+    fn aliases(&self) -> &'static [&'static str];
+    /// Returns the first pattern of the variant matching the str
+    fn match_details(&self, other: &str) -> Option<StrMatch<'_>>;
+}
+/// Adds aliases to the patterns of a `CompareToStr` enum at runtime.
+///
+/// Strings are resolved in this order. Compile time `equals` patterns and variant names.
+/// Then runtime aliases. Then the other compile time patterns like `contains`
+/// Aliases are added while building the matcher. It can then be shared between threads. For example in an `Arc`
+///
+/// ```rust,ignore
+/// let matcher = StrMatcher::new()
+///     .with_alias(Header::ContentType, "x-customer-content-type");
+/// assert_eq!(matcher.resolve("x-customer-content-type"), Some(Header::ContentType));
+/// ```
+#[derive(Debug, Clone)]
+pub struct StrMatcher<E> {
+    /// The variant of each alias. Keys are normalized for the case mode of `E`
+    aliases: HashMap<String, E>,
+}
+impl<E: StrPatterns> Default for StrMatcher<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<E: StrPatterns> StrMatcher<E> {
+    /// A matcher with only the compile time patterns
+    pub fn new() -> Self {
+        Self {
+            aliases: HashMap::new(),
+        }
+    }
+    /// Adds an alias for the variant of `value`. Replaces the variant of an alias added before
+    pub fn add_alias(&mut self, value: E, alias: impl AsRef<str>) {
+        let alias = E::CASE_MODE.normalize(alias.as_ref()).into_owned();
+        self.aliases.insert(alias, value);
+    }
+    pub fn with_alias(mut self, value: E, alias: impl AsRef<str>) -> Self {
+        self.add_alias(value, alias);
+        self
+    }
+    fn runtime_alias(&self, other: &str) -> Option<&E> {
+        self.aliases.get(E::CASE_MODE.normalize(other).as_ref())
+    }
+    /// Checks if the value matches the string. Runtime aliases match any value of the same variant
+    pub fn matches(&self, value: &E, other: &str) -> bool {
+        value.equals_str(other)
+            || self
+                .runtime_alias(other)
+                .is_some_and(|alias| discriminant(alias) == discriminant(value))
+    }
+    /// Finds the variant matching the string. A runtime alias returns a clone of the value it was added with.
+    ///
+    /// A runtime alias is exact. So it is preferred over compile time patterns that are not
+    pub fn resolve(&self, other: &str) -> Option<E>
+    where
+        E: Clone,
+    {
+        match E::match_str(other) {
+            Some(value)
+                if value.match_details(other).is_some_and(|details| {
+                    matches!(details.kind, MatchKind::Equals | MatchKind::VariantName)
+                }) =>
+            {
+                Some(value)
+            }
+            value => self.runtime_alias(other).cloned().or(value),
+        }
+    }
+    /// The compile time and runtime aliases of the variant of `value`.
+    ///
+    /// Runtime aliases are normalized for the case mode
+    pub fn aliases<'a>(&'a self, value: &'a E) -> impl Iterator<Item = &'a str> + 'a {
+        let value_discriminant = discriminant(value);
+        value.aliases().iter().copied().chain(
+            self.aliases
+                .iter()
+                .filter(move |(_, alias)| discriminant(*alias) == value_discriminant)
+                .map(|(alias, _)| alias.as_str()),
+        )
+    }
+}
+/// Returned when a string does not match any variant
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseVariantError {
+    pub value: String,
+    /// The closest accepted spelling. From the `suggest` function generated by `CompareToStr`
+    pub suggestion: Option<&'static str>,
+}
+impl ParseVariantError {
+    pub fn new(value: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            suggestion: None,
+        }
+    }
+    pub fn with_suggestion(mut self, suggestion: Option<&'static str>) -> Self {
+        self.suggestion = suggestion;
+        self
+    }
+}
+impl std::fmt::Display for ParseVariantError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "`{}` does not match any variant", self.value)?;
+        if let Some(suggestion) = self.suggestion {
+            write!(f, ". Did you mean `{suggestion}`?")?;
+        }
+        Ok(())
+    }
+}
+impl std::error::Error for ParseVariantError {}
+/// Checks if the bytes are equal in a const context. ASCII letters of `value` are lowercased if `ignore_ascii_case` is set
+pub const fn const_eq(value: &[u8], pattern: &[u8], ignore_ascii_case: bool) -> bool {
+    if value.len() != pattern.len() {
+        return false;
+    }
+    const_eq_at(value, 0, pattern, ignore_ascii_case)
+}
+/// The const version of `starts_with`
+pub const fn const_starts_with(value: &[u8], pattern: &[u8], ignore_ascii_case: bool) -> bool {
+    value.len() >= pattern.len() && const_eq_at(value, 0, pattern, ignore_ascii_case)
+}
+/// The const version of `ends_with`
+pub const fn const_ends_with(value: &[u8], pattern: &[u8], ignore_ascii_case: bool) -> bool {
+    value.len() >= pattern.len()
+        && const_eq_at(
+            value,
+            value.len() - pattern.len(),
+            pattern,
+            ignore_ascii_case,
+        )
+}
+/// Compares `pattern` to the bytes of `value` starting at `start`. `value` must be long enough
+const fn const_eq_at(value: &[u8], start: usize, pattern: &[u8], ignore_ascii_case: bool) -> bool {
+    let mut index = 0;
+    while index < pattern.len() {
+        let mut byte = value[start + index];
+        if ignore_ascii_case {
+            byte = byte.to_ascii_lowercase();
+        }
+        if byte != pattern[index] {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+/// A segment of a glob pattern between two `*`.
+///
+/// Returns the length in bytes of the prefix of the string it matches
+pub type GlobSegment = fn(&str) -> Option<usize>;
+
+/// Matches a glob compiled by `CompareToStr`.
+///
+/// `segments` are the parts of the pattern between each `*`. So `x-*` is `["x-", ""]`
+pub fn glob_matches(value: &str, segments: &[GlobSegment]) -> bool {
+    match segments {
+        [] => value.is_empty(),
+        [only] => only(value) == Some(value.len()),
+        [first, middle @ .., last] => {
+            let Some(mut pos) = first(value) else {
+                return false;
+            };
+            for segment in middle {
+                let found = char_starts(value, pos)
+                    .find_map(|start| segment(&value[start..]).map(|len| start + len));
+                match found {
+                    Some(end) => pos = end,
+                    None => return false,
+                }
+            }
+            char_starts(value, pos).any(|start| last(&value[start..]) == Some(value.len() - start))
+        }
+    }
+}
+/// Every char boundary of `value` starting at `from`. Including the end of the string
+fn char_starts(value: &str, from: usize) -> impl Iterator<Item = usize> + '_ {
+    value[from..]
+        .char_indices()
+        .map(move |(index, _)| from + index)
+        .chain(std::iter::once(value.len()))
+}
+/// The longest string [CaseMode::edit_distance] compares against without allocating
+pub const EDIT_DISTANCE_STACK_LEN: usize = 64;
+/// The Unicode full case folding of a character. `ß`, `ẞ` and `SS` all fold to `ss`.
+///
+/// Built from the case mappings of the standard library by lowercasing the uppercase of the lowercase.
+/// This compares the same strings as equal as `CaseFolding.txt` does. Cherokee folds to lowercase instead of uppercase.
+/// The Turkic mappings of `I` and `İ` are not applied
+pub fn fold_char(c: char) -> impl DoubleEndedIterator<Item = char> {
+    // `ı` folds to itself. Its uppercase `I` would turn it into `i`
+    let dotless_i = c == 'ı';
+    c.to_lowercase()
+        .flat_map(char::to_uppercase)
+        .flat_map(char::to_lowercase)
+        .map(move |folded| if dotless_i { 'ı' } else { folded })
+}
+/// The length in bytes of the prefix of `value` whose folded characters start with the folded `pattern`.
+///
+/// The first `skip` folded characters of `value` are ignored. So a match can start inside a character
+fn fold_prefix_len(value: &str, pattern: &str, mut skip: usize) -> Option<usize> {
+    let mut pattern = pattern.chars().peekable();
+    if pattern.peek().is_none() {
+        return Some(0);
+    }
+    for (index, c) in value.char_indices() {
+        for folded in fold_char(c).skip(std::mem::take(&mut skip)) {
+            match pattern.next() {
+                Some(expected) if expected == folded => {}
+                Some(_) => return None,
+                None => return Some(index + c.len_utf8()),
+            }
+        }
+        if pattern.peek().is_none() {
+            return Some(index + c.len_utf8());
+        }
+    }
+    None
+}
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+/// The segments of the path joined with `::`. Generic arguments and a leading `::` are left out.
+///
+/// `::std::vec::Vec<u8>` becomes `std::vec::Vec`. Every suffix of it can be checked with [CaseMode::ends_with_path]
+#[cfg(feature = "syn")]
+pub fn path_to_string(path: &syn::Path) -> String {
+    use std::fmt::Write;
+    let mut result = String::new();
+    for segment in &path.segments {
+        if !result.is_empty() {
+            result.push_str("::");
+        }
+        write!(result, "{}", segment.ident).expect("Writing to a String can not fail");
+    }
+    result
+}
+/// The path of a type. None if the type is not a path or has a qualified self like `<T as Trait>::Type`
+#[cfg(feature = "syn")]
+pub fn type_path(ty: &syn::Type) -> Option<&syn::Path> {
+    match ty {
+        syn::Type::Path(type_path) if type_path.qself.is_none() => Some(&type_path.path),
+        syn::Type::Group(group) => type_path(&group.elem),
+        syn::Type::Paren(paren) => type_path(&paren.elem),
+        _ => None,
+    }
+}
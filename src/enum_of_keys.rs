@@ -1,5 +1,38 @@
-pub trait KeyEnum {}
-impl<T: KeyEnum> KeyEnum for &'_ T {}
+pub trait KeyEnum {
+    /// The description of the variant.
+    ///
+    /// Taken from `#[enum_of_keys(description = "...")]` or the doc comment of the variant
+    fn description(&self) -> Option<&'static str> {
+        None
+    }
+}
+impl<T: KeyEnum> KeyEnum for &'_ T {
+    fn description(&self) -> Option<&'static str> {
+        (*self).description()
+    }
+}
+/// Returned when a KeyEnum can not be converted back into a value of the original enum.
+///
+/// Only unit variants, the default variant and variants marked with `#[enum_of_keys(from_default)]` can be built from their key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FromKeyError<K> {
+    pub key: K,
+}
+impl<K> FromKeyError<K> {
+    pub fn new(key: K) -> Self {
+        Self { key }
+    }
+    /// Returns the key that could not be converted
+    pub fn into_key(self) -> K {
+        self.key
+    }
+}
+impl<K: std::fmt::Debug> std::fmt::Display for FromKeyError<K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "The key {:?} can not be converted into a value", self.key)
+    }
+}
+impl<K: std::fmt::Debug> std::error::Error for FromKeyError<K> {}
 pub trait HasKeyEnum {
     type KeyEnum<'a>: KeyEnum
     where
@@ -10,7 +43,7 @@ pub trait HasKeyEnum {
     fn get_key_borrowed(&self) -> Self::KeyEnum<'_>;
 }
 
-impl<'b, T> HasKeyEnum for &'b T
+impl<T> HasKeyEnum for &'_ T
 where
     T: HasKeyEnum,
 {
@@ -24,6 +57,31 @@ where
         (*self).get_key_borrowed()
     }
 }
+/// A zero-sized type representing a single variant of `Source`.
+///
+/// Generated by `#[enum_of_keys(KeyEnumName, markers)]`.
+/// The payload of a variant with one field is that field. Multiple fields are a tuple in declaration order. Unit variants are `()`
+pub trait VariantMarker<Source> {
+    /// The owned fields of the variant
+    type Payload;
+    /// References to the fields of the variant
+    type PayloadRef<'a>
+    where
+        Source: 'a;
+    /// Mutable references to the fields of the variant
+    type PayloadMut<'a>
+    where
+        Source: 'a;
+
+    /// Returns the fields if the value is this variant
+    fn extract(value: &Source) -> Option<Self::PayloadRef<'_>>;
+
+    /// Returns mutable references to the fields if the value is this variant
+    fn extract_mut(value: &mut Source) -> Option<Self::PayloadMut<'_>>;
+
+    /// Takes the fields if the value is this variant. Otherwise returns the value back
+    fn into_payload(value: Source) -> Result<Self::Payload, Source>;
+}
 pub trait LookupByKey<'a> {
     type HasKeyEnum: HasKeyEnum;
     fn has_key(self, key: &'a <Self::HasKeyEnum as HasKeyEnum>::KeyEnum<'a>) -> bool;
@@ -37,6 +95,9 @@ pub trait LookupByKey<'a> {
         self,
         key: &'a <Self::HasKeyEnum as HasKeyEnum>::KeyEnum<'a>,
     ) -> Vec<&'a Self::HasKeyEnum>;
+
+    /// Finds the first value of the variant `M` and returns its fields
+    fn get_payload<M: VariantMarker<Self::HasKeyEnum>>(self) -> Option<M::PayloadRef<'a>>;
 }
 impl<'a, I, E> LookupByKey<'a> for I
 where
@@ -63,4 +124,8 @@ where
     ) -> Vec<&'a Self::HasKeyEnum> {
         self.into_iter().filter(|e| e.eq(key)).collect()
     }
+
+    fn get_payload<M: VariantMarker<Self::HasKeyEnum>>(self) -> Option<M::PayloadRef<'a>> {
+        self.into_iter().find_map(|e| M::extract(e))
+    }
 }
@@ -1,3 +1,4 @@
+pub mod compare_str;
 mod enum_of_keys;
 
 #[cfg(feature = "derive")]
@@ -7,15 +8,99 @@ pub use enum_helpers_macros::EnumOfKeys;
 /// Allows you to compare an enum to a string
 /// # Available Container Attributes
 /// - partial_eq: bool.
-/// Defaults to true.
-/// If true will implement PartialEq<str> and PartialEq<&str> for the enum
+///   Defaults to true.
+///   If true will implement PartialEq<str>, PartialEq<&str>, PartialEq<String> and PartialEq<Cow<str>> for the enum.
+///   Along with the reversed impls so `"value" == enum_value` works
+/// - include_variant: bool.
+///   Defaults to true.
+///   If true the name of the variant is also an `equals` pattern
+/// - rename_all: "casing" or ["casing", ...].
+///   The casings of the variant name matched by `include_variant`. Defaults to the name as written.
+///   One of `lowercase`, `UPPERCASE`, `PascalCase`, `camelCase`, `snake_case`, `SCREAMING_SNAKE_CASE`, `kebab-case` or `SCREAMING-KEBAB-CASE`.
+///   `rename_all["kebab-case", "snake_case"]` matches both `file-path` and `file_path`
+///   `EnumOfKeys` forwards it to the KeyEnum. Strum names of the KeyEnum still come from `serialize_all`
+/// - bytes: bool.
+///   Defaults to false.
+///   If true will generate `equals_bytes(&[u8])` and `equals_os_str(&OsStr)`. Along with PartialEq<[u8]> and PartialEq<&[u8]> if partial_eq is set.
+///   Bytes are compared without converting them to a str. `word`, `glob` and `case_fold` patterns only match valid UTF-8
+/// - ignore_ascii_case: bool.
+///   Defaults to false.
+///   If true will compare ignoring ASCII case. Non ASCII letters must match exactly
+/// - case_fold: bool.
+///   Defaults to false.
+///   If true will compare the Unicode full case folding of the string. So `STRASSE` equals `Straße`
+///   Other patterns also compare the folded characters. So `contains["se"]` matches `maße`
 /// - to_lowercase: bool.
-/// Defaults to false.
-/// If true will lowercase the string before comparing
+///   Same as case_fold.
+/// - from_str: bool.
+///   Defaults to false.
+///   If true will implement FromStr using `match_str`
+/// - key: Type.
+///   Generates `match_key(&str) -> Option<Key>` returning the KeyEnum of the matching variant.
+///   The `#[enum_of_keys(default)]` variant is built from the string.
+///   With `default_in_cow` use `key = Keys<'_>` to borrow the string. `key = Keys<'static>` copies it
+/// - allow_overlap: bool.
+///   Defaults to false.
+///   If false a warning is emitted when a pattern of one variant also matches a pattern of another variant.
+///   Can also be set on a single variant with `#[compare_str(allow_overlap)]`
+///
+/// Patterns are normalized when the macro expands. Comparisons never allocate.
+/// The exceptions are `with` and `with_self`. The string is copied to normalize it for the predicate unless it is already normalized.
+/// And `similar` when an `equals` pattern is longer than 64 characters
+///
+/// Generic enums are supported. Generics and where clauses are carried to every generated impl
+///
+/// `match_details(&self, &str) -> Option<StrMatch>` reports the kind, literal and byte range of the pattern that matched.
+///
+/// `aliases()` and `substrings()` return the `equals` and `contains` patterns of a variant as written.
+/// `PATTERNS` lists every variant name with its patterns in declaration order
+///
+/// `suggest(&str) -> Option<&'static str>` returns the closest `equals` pattern or key name. `FromStr` adds it to the error
+///
+/// `StrPatterns` is implemented for the enum. So more aliases can be added at runtime with `compare_str::StrMatcher`
+///
+/// `equals_str_const(&self, &str)` and `match_str_const(&str)` are const fns. They only check `equals`, `starts_with` and `ends_with` patterns.
+/// They are not generated with `case_fold`
+///
+/// With the `syn` feature `equals_path(&syn::Path)` and `equals_type(&syn::Type)` are generated.
+/// Only `equals` and `path_suffix` patterns are checked. They are compared to the trailing segments of the path. Generic arguments are ignored
+///
+/// `match_str(&str) -> Option<Self>` finds the unit variant matching a string.
+/// `equals` patterns of every variant are checked before any other pattern. Ties are broken by declaration order
+///
+/// `match_str` and `match_key` dispatch `equals` patterns on the length and bytes of the string.
+/// Enums with many `contains` patterns search for all of them in a single pass
 /// # Available Field Attributes
 /// - equals: [&str]. An Array of strings to compare to
+/// - equals_from: &str. A file of `equals` patterns. Relative to the `CARGO_MANIFEST_DIR` of the crate.
+///   One pattern per line. Empty lines and lines starting with `#` are skipped. Cargo rebuilds the crate when the file changes
 /// - contains: [&str].
-/// An Array of strings to check if the string contains
+///   An Array of strings to check if the string contains
+/// - starts_with: [&str]. The string starts with one of the strings
+/// - ends_with: [&str]. The string ends with one of the strings
+/// - word: [&str]. The string contains one of the strings as a whole word.
+///   Words are separated by anything that is not alphanumeric or `_`
+/// - path_suffix: [&str]. The last segments of the `::` separated path are one of the strings.
+///   `path_suffix["Uuid"]` matches `Uuid` and `uuid::Uuid` but not `MyUuid`
+/// - glob: [&str]. The string matches one of the glob patterns.
+///   Supports `*`, `?`, `[abc]`, `[a-z]`, `[!abc]` and `\` to escape. Globs are compiled when the macro expands
+/// - field: Compares the string to a field of the variant implementing `AsRef<str>`.
+///   `field` for a variant with a single field. `field = name` or `field = 0` to pick one.
+///   Only used when comparing a value. `match_str` and `match_key` ignore it
+/// - except: [&str]. Strings the variant never matches. Even if one of its patterns matches them.
+///   `contains["uuid"], except["uuid_legacy"]`
+/// - with: path. A function `fn(&str) -> bool` matching the variant.
+///   It receives the string normalized for the case mode. So it is ASCII lowercase with `ignore_ascii_case` and case folded with `case_fold`
+///   Normalizing allocates if the string is not already lowercase or case folded
+/// - with_self: path. A function `fn(&Self, &str) -> bool` that can read the fields of the variant.
+///   Only used when comparing a value. `match_str` and `match_key` ignore it
+/// - similar or similar[threshold = 2]: Also match strings a few edits away from an `equals` pattern. The threshold defaults to 1.
+///   `match_str` and `match_key` only accept a near miss if no pattern matched
+/// - fallback: The variant matches any string not matched by the patterns of another variant.
+///   `match_str` and `match_key` return it last. Only one variant can be the fallback
+///
+/// A variant matches if any of its patterns match.
+/// Declaring the same pattern on two variants is a compile error
 /// # Example
 /// ```rust,ignore
 /// use enum_helpers_macros::CompareToStr;
@@ -1,5 +1,4 @@
 use enum_helpers_macros::{CompareToStr, EnumOfKeys};
-use strum::EnumString;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum UUIDFormat {
@@ -66,3 +65,753 @@ pub fn test() {
     assert_eq!(OtherTypes::FilePath, "PathBuf");
     assert_eq!(OtherTypes::FilePath, "Path");
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, CompareToStr)]
+#[compare_str(ignore_ascii_case)]
+pub enum AsciiCaseTypes {
+    #[compare_str(equals["Uuid"], contains["UUID"])]
+    UUID,
+    #[compare_str(equals["PathBuf"])]
+    FilePath,
+    #[compare_str(equals["café"])]
+    Cafe,
+}
+#[derive(Debug, Clone, PartialEq, Eq, CompareToStr)]
+#[compare_str(case_fold)]
+pub enum CaseFoldTypes {
+    #[compare_str(equals["Straße"], contains["ÜBER"])]
+    Street,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, CompareToStr)]
+#[compare_str(case_fold, include_variant = false)]
+pub enum CaseFoldPartTypes {
+    #[compare_str(contains["sse"], ends_with["aß"])]
+    Mass,
+    #[compare_str(starts_with["STRAS"])]
+    Street,
+}
+
+#[test]
+pub fn test_case_modes() {
+    assert_eq!(AsciiCaseTypes::UUID, "uuid");
+    assert_eq!(AsciiCaseTypes::UUID, "UUID");
+    assert_eq!(AsciiCaseTypes::UUID, "my_Uuid_type");
+    assert_eq!(AsciiCaseTypes::FilePath, "pathbuf");
+    assert_eq!(AsciiCaseTypes::FilePath, "filepath");
+    assert_ne!(AsciiCaseTypes::FilePath, "path");
+    assert_eq!(AsciiCaseTypes::Cafe, "CAFé");
+    assert_ne!(AsciiCaseTypes::Cafe, "CAFÉ");
+    assert_eq!(
+        AsciiCaseTypes::match_str("CAFé"),
+        Some(AsciiCaseTypes::Cafe)
+    );
+
+    assert_eq!(CaseFoldTypes::Street, "STRAßE");
+    assert_eq!(CaseFoldTypes::Street, "straße");
+    assert_eq!(CaseFoldTypes::Street, "das über ding");
+    assert_eq!(CaseFoldTypes::Street, "STRASSE");
+    assert_eq!(CaseFoldTypes::Street, "strasse");
+    assert_eq!(CaseFoldTypes::Street, "STRAẞE");
+    assert_ne!(CaseFoldTypes::Street, "strase");
+    // Patterns compare the folded characters. So they can match part of `ß`
+    use enum_helper::compare_str::CaseMode;
+    assert!(CaseMode::CaseFold.eq("ß", "ss"));
+    assert!(CaseMode::CaseFold.starts_with("ßa", "s"));
+    assert!(CaseMode::CaseFold.ends_with("maß", "s"));
+    assert!(CaseMode::CaseFold.contains("maß", "s"));
+    assert!(CaseMode::CaseFold.contains("maße", "se"));
+    assert!(!CaseMode::CaseFold.contains("maße", "sse2"));
+    assert_eq!(CaseMode::CaseFold.find("maße", "se"), Some(2..5));
+    assert_eq!(CaseFoldPartTypes::Mass, "MAẞE");
+    assert_eq!(CaseFoldPartTypes::Mass, "Maß");
+    assert_eq!(CaseFoldPartTypes::Street, "Straße");
+    assert_ne!(CaseFoldPartTypes::Street, "Strae");
+    assert_eq!(
+        CaseFoldTypes::match_str("STRASSE"),
+        Some(CaseFoldTypes::Street)
+    );
+
+    assert!(OtherTypesNoPartialEq::FilePath.equals_str("pathbuf"));
+    assert!(OtherTypesNoPartialEq::UUID {
+        format: UUIDFormat::Bytes,
+        version: UUIDVersion::Version1,
+    }
+    .equals_str("UUID::UUID"));
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, CompareToStr)]
+#[compare_str(include_variant = false)]
+pub enum Matchers {
+    #[compare_str(path_suffix["uuid::Uuid"], equals["Uuid"])]
+    UUID,
+    #[compare_str(starts_with["std::"])]
+    Std,
+    #[compare_str(ends_with["Error"])]
+    Error,
+    #[compare_str(word["gzip"])]
+    Gzip,
+}
+#[derive(Debug, Clone, PartialEq, Eq, CompareToStr)]
+#[compare_str(ignore_ascii_case, include_variant = false)]
+pub enum MatchersIgnoreCase {
+    #[compare_str(path_suffix["Uuid"], starts_with["X-"], ends_with["-Legacy"], word["GZIP"])]
+    Any,
+}
+
+#[test]
+pub fn test_matchers() {
+    assert_eq!(Matchers::UUID, "uuid::Uuid");
+    assert_eq!(Matchers::UUID, "::uuid::Uuid");
+    assert_eq!(Matchers::UUID, "Uuid");
+    assert_ne!(Matchers::UUID, "my_uuid::Uuid");
+    assert_eq!(Matchers::Std, "std::path::PathBuf");
+    assert_ne!(Matchers::Std, "core::str");
+    assert_eq!(Matchers::Error, "std::io::Error");
+    assert_eq!(Matchers::Gzip, "deflate, gzip");
+    assert_eq!(Matchers::Gzip, "gzip;q=1.0");
+    assert_ne!(Matchers::Gzip, "x-gzipped");
+
+    assert_eq!(MatchersIgnoreCase::Any, "my::UUID");
+    assert_ne!(MatchersIgnoreCase::Any, "MyUuid");
+    assert_eq!(MatchersIgnoreCase::Any, "x-custom");
+    assert_eq!(MatchersIgnoreCase::Any, "thing-LEGACY");
+    assert_eq!(MatchersIgnoreCase::Any, "br, Gzip");
+    assert_ne!(MatchersIgnoreCase::Any, "other");
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, CompareToStr)]
+#[compare_str(ignore_ascii_case, include_variant = false)]
+pub enum Headers {
+    #[compare_str(glob["x-*", "*-legacy"])]
+    Vendor,
+    #[compare_str(glob["content-?ength", "accept-[a-z]*[!0-9]"])]
+    Known,
+    #[compare_str(glob["\\*literal"])]
+    Escaped,
+}
+
+#[test]
+pub fn test_glob() {
+    assert_eq!(Headers::Vendor, "X-ANYTHING");
+    assert_eq!(Headers::Vendor, "x-");
+    assert_eq!(Headers::Vendor, "Auth-Legacy");
+    assert_ne!(Headers::Vendor, "Authorization");
+    assert_eq!(Headers::Known, "Content-Length");
+    assert_ne!(Headers::Known, "Content-Lengths");
+    assert_eq!(Headers::Known, "Accept-Encoding");
+    assert_ne!(Headers::Known, "Accept-Version2");
+    assert_ne!(Headers::Known, "Accept-");
+    assert_eq!(Headers::Escaped, "*literal");
+    assert_ne!(Headers::Escaped, "a literal");
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, CompareToStr, EnumOfKeys)]
+#[enum_of_keys(ExtensionKeys, impl_common_traits)]
+#[compare_str(from_str, key = ExtensionKeys, include_variant = false)]
+pub enum Extension {
+    #[compare_str(contains["uuid"], allow_overlap)]
+    Loose,
+    #[compare_str(equals["uuidv7"])]
+    Exact,
+    #[compare_str(equals["SIZE"])]
+    Size(u64),
+    #[enum_of_keys(default)]
+    #[compare_str(starts_with["X-"])]
+    Other(String),
+}
+
+#[test]
+pub fn test_match_str() {
+    use std::str::FromStr;
+    assert_eq!(Extension::match_str("uuidv7"), Some(Extension::Exact));
+    assert_eq!(Extension::match_str("my_uuid"), Some(Extension::Loose));
+    assert_eq!(Extension::match_str("SIZE"), None);
+    assert_eq!(Extension::match_key("SIZE"), Some(ExtensionKeys::Size));
+    assert_eq!(Extension::match_key("uuidv7"), Some(ExtensionKeys::Exact));
+    assert_eq!(
+        Extension::match_key("X-FOO"),
+        Some(ExtensionKeys::Other("X-FOO".into()))
+    );
+    assert_eq!(Extension::from_str("uuid"), Ok(Extension::Loose));
+    assert_eq!(
+        Extension::from_str("nothing").unwrap_err().to_string(),
+        "`nothing` does not match any variant"
+    );
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, CompareToStr, EnumOfKeys)]
+#[enum_of_keys(CowExtensionKeys, default_in_cow, impl_common_traits)]
+#[compare_str(key = CowExtensionKeys<'static>)]
+pub enum CowExtension {
+    #[compare_str(equals["json"])]
+    Json,
+    #[enum_of_keys(default)]
+    #[compare_str(starts_with["X-"])]
+    Other(String),
+}
+#[derive(Debug, Clone, PartialEq, Eq, CompareToStr, EnumOfKeys)]
+#[enum_of_keys(BorrowedExtensionKeys, default_in_cow, impl_common_traits)]
+#[compare_str(key = BorrowedExtensionKeys<'_>)]
+pub enum BorrowedExtension {
+    #[compare_str(equals["json"])]
+    Json,
+    #[enum_of_keys(default)]
+    #[compare_str(starts_with["X-"])]
+    Other(String),
+}
+
+#[test]
+pub fn test_match_key_cow() {
+    use std::borrow::Cow;
+    let owned = CowExtension::match_key(&String::from("X-FOO"));
+    assert!(matches!(
+        owned,
+        Some(CowExtensionKeys::Other(Cow::Owned(ref value))) if value == "X-FOO"
+    ));
+    assert_eq!(CowExtension::match_key("json"), Some(CowExtensionKeys::Json));
+    assert!(matches!(
+        BorrowedExtension::match_key("X-FOO"),
+        Some(BorrowedExtensionKeys::Other(Cow::Borrowed("X-FOO")))
+    ));
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, CompareToStr)]
+#[compare_str(ignore_ascii_case, include_variant = false, allow_overlap)]
+pub enum Mime {
+    #[compare_str(equals["text/plain", "text"], contains["plain"])]
+    Text,
+    #[compare_str(equals["application/json"], contains["json", "+json"])]
+    Json,
+    #[compare_str(contains["xml"], ends_with["+xml"])]
+    Xml,
+    #[compare_str(contains["html", "htm"])]
+    Html,
+    #[compare_str(word["image"], contains["png"])]
+    Image,
+}
+
+#[test]
+pub fn test_dispatch() {
+    assert_eq!(Mime::match_str("TEXT/PLAIN"), Some(Mime::Text));
+    assert_eq!(Mime::match_str("Application/Json"), Some(Mime::Json));
+    assert_eq!(Mime::match_str("application/ld+JSON"), Some(Mime::Json));
+    assert_eq!(Mime::match_str("application/xhtml+xml"), Some(Mime::Xml));
+    assert_eq!(Mime::match_str("text/HTM"), Some(Mime::Html));
+    assert_eq!(Mime::match_str("plain json"), Some(Mime::Text));
+    assert_eq!(Mime::match_str("image/x-icon"), Some(Mime::Image));
+    assert_eq!(Mime::match_str("image/png+json"), Some(Mime::Json));
+    assert_eq!(Mime::match_str("font/woff2"), None);
+    assert_eq!(Mime::match_str(""), None);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, CompareToStr)]
+#[compare_str(ignore_ascii_case)]
+pub enum HeaderName {
+    #[compare_str(equals["Content-Type"])]
+    ContentType,
+    #[compare_str(field)]
+    Other(String),
+    #[compare_str(field = name, equals["custom"])]
+    Custom { name: Box<str>, value: u32 },
+    #[compare_str(field = 1)]
+    Pair(u32, &'static str),
+}
+
+#[test]
+pub fn test_field() {
+    assert_eq!(HeaderName::Other("X-FOO".into()), "X-FOO");
+    assert_eq!(HeaderName::Other("X-FOO".into()), "x-foo");
+    assert_eq!(HeaderName::Other("X-FOO".into()), "Other");
+    assert_ne!(HeaderName::Other("X-FOO".into()), "X-BAR");
+    let custom = HeaderName::Custom {
+        name: "X-Custom".into(),
+        value: 1,
+    };
+    assert_eq!(custom, "x-custom");
+    assert_eq!(custom, "CUSTOM");
+    assert_eq!(HeaderName::Pair(1, "second"), "SECOND");
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, CompareToStr, EnumOfKeys)]
+#[enum_of_keys(HeaderNameWithKeysKeys, impl_common_traits)]
+pub enum HeaderNameWithKeys {
+    #[compare_str(field = name)]
+    Custom { name: String, value: u32 },
+    #[enum_of_keys(default)]
+    #[compare_str(field)]
+    Other(String),
+}
+
+#[test]
+pub fn test_field_with_keys() {
+    use enum_helper::HasKeyEnum;
+    let other = HeaderNameWithKeys::Other("X-FOO".into());
+    assert_eq!(other, "X-FOO");
+    assert_eq!(other.get_key(), "X-FOO");
+    let custom = HeaderNameWithKeys::Custom {
+        name: "X-Custom".into(),
+        value: 1,
+    };
+    assert_eq!(custom, "X-Custom");
+    assert_eq!(custom.get_key(), "Custom");
+    assert_ne!(custom.get_key(), "X-Custom");
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, CompareToStr, EnumOfKeys)]
+#[compare_str(ignore_ascii_case)]
+#[enum_of_keys(AsciiCaseKeyTypesKeys, impl_common_traits)]
+pub enum AsciiCaseKeyTypes {
+    #[compare_str(equals["alpha"])]
+    FirstOne,
+    #[enum_of_keys(default)]
+    #[compare_str(field)]
+    Other(String),
+}
+
+#[test]
+pub fn test_case_mode_key_enum() {
+    use enum_helper::HasKeyEnum;
+    assert_eq!(AsciiCaseKeyTypes::FirstOne, "ALPHA");
+    assert_eq!(AsciiCaseKeyTypesKeys::FirstOne, "ALPHA");
+    assert_eq!(AsciiCaseKeyTypesKeys::FirstOne, "FIRSTONE");
+    let other = AsciiCaseKeyTypes::Other("X-Foo".into());
+    assert_eq!(other, "x-foo");
+    assert_eq!(other.get_key(), "x-foo");
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, CompareToStr, EnumOfKeys)]
+#[compare_str(include_variant = false)]
+#[enum_of_keys(SwappedNameTypesKeys, impl_common_traits)]
+pub enum SwappedNameTypes {
+    #[compare_str(equals["B"])]
+    A,
+    B,
+}
+
+#[test]
+pub fn test_include_variant_key_enum() {
+    assert_eq!(SwappedNameTypes::A, "B");
+    assert_eq!(SwappedNameTypesKeys::A, "B");
+    assert_ne!(SwappedNameTypes::B, "B");
+    assert_ne!(SwappedNameTypesKeys::B, "B");
+}
+
+#[test]
+pub fn test_partial_eq_types() {
+    use std::borrow::Cow;
+    let owned = String::from("PathBuf");
+    assert_eq!(OtherTypes::FilePath, owned);
+    assert_eq!(owned, OtherTypes::FilePath);
+    assert_eq!(OtherTypes::FilePath, Cow::Borrowed("Path"));
+    assert_eq!(Cow::<str>::Owned("Path".to_owned()), OtherTypes::FilePath);
+    assert_eq!("Path", OtherTypes::FilePath);
+    assert_eq!(*"Path", OtherTypes::FilePath);
+    assert_ne!("uuid::Uuid", OtherTypes::FilePath);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, CompareToStr)]
+#[compare_str(rename_all = "kebab-case")]
+pub enum KebabTypes {
+    FilePath,
+    StartTLS,
+    HTTPServer,
+}
+#[derive(Debug, Clone, PartialEq, Eq, CompareToStr)]
+#[compare_str(rename_all["snake_case", "SCREAMING_SNAKE_CASE", "camelCase"])]
+pub enum ManyCasingTypes {
+    FilePath,
+    Uuid,
+}
+
+#[test]
+pub fn test_rename_all() {
+    assert_eq!(KebabTypes::FilePath, "file-path");
+    assert_ne!(KebabTypes::FilePath, "FilePath");
+    assert_eq!(KebabTypes::StartTLS, "start-tls");
+    assert_eq!(KebabTypes::HTTPServer, "http-server");
+
+    assert_eq!(ManyCasingTypes::FilePath, "file_path");
+    assert_eq!(ManyCasingTypes::FilePath, "FILE_PATH");
+    assert_eq!(ManyCasingTypes::FilePath, "filePath");
+    assert_ne!(ManyCasingTypes::FilePath, "file-path");
+    assert_eq!(ManyCasingTypes::Uuid, "uuid");
+    assert_eq!(ManyCasingTypes::Uuid, "UUID");
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, CompareToStr, EnumOfKeys)]
+#[compare_str(rename_all = "kebab-case")]
+#[enum_of_keys(KebabKeyTypesKeys, impl_common_traits)]
+pub enum KebabKeyTypes {
+    FirstOne,
+    #[compare_str(equals["second"])]
+    SecondOne(String),
+}
+
+#[test]
+pub fn test_rename_all_key_enum() {
+    let values = [
+        (KebabKeyTypes::FirstOne, KebabKeyTypesKeys::FirstOne),
+        (
+            KebabKeyTypes::SecondOne(String::new()),
+            KebabKeyTypesKeys::SecondOne,
+        ),
+    ];
+    for (value, key) in values {
+        for input in ["first-one", "FirstOne", "second-one", "second"] {
+            assert_eq!(value.equals_str(input), key.equals_str(input), "{input}");
+        }
+    }
+    assert_eq!(
+        KebabKeyTypesKeys::match_str("first-one"),
+        Some(KebabKeyTypesKeys::FirstOne)
+    );
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, CompareToStr)]
+#[compare_str(from_str)]
+pub enum GenericValue<'a, T>
+where
+    T: AsRef<str>,
+{
+    #[compare_str(field)]
+    Text(T),
+    #[compare_str(field)]
+    Borrowed(&'a str),
+    #[compare_str(equals["null"])]
+    Null,
+}
+
+#[test]
+pub fn test_generics() {
+    let text: GenericValue<'_, String> = GenericValue::Text("hello".to_owned());
+    assert_eq!(text, "hello");
+    assert_eq!("hello", text);
+    assert_eq!(GenericValue::<String>::Borrowed("world"), "world");
+    assert_eq!(GenericValue::<String>::Null, "null");
+    assert_eq!(
+        "null".parse::<GenericValue<String>>().ok(),
+        Some(GenericValue::Null)
+    );
+    assert_eq!(GenericValue::<String>::match_str("hello"), None);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, CompareToStr)]
+#[compare_str(bytes, ignore_ascii_case)]
+pub enum ByteTypes {
+    #[compare_str(equals["GET"], starts_with["x-get-"])]
+    Get,
+    #[compare_str(ends_with[".tar.gz"], path_suffix["tar::Archive"], word["tar"])]
+    Archive,
+    #[compare_str(field)]
+    Other(String),
+}
+
+#[test]
+pub fn test_bytes() {
+    assert!(ByteTypes::Get.equals_bytes(b"get"));
+    assert!(ByteTypes::Get.equals_bytes(b"X-Get-Thing"));
+    assert_eq!(ByteTypes::Get, b"GET"[..]);
+    assert_eq!(&b"GeT"[..], ByteTypes::Get);
+    assert!(!ByteTypes::Get.equals_bytes(b"\xffGET"));
+
+    assert!(ByteTypes::Archive.equals_bytes(b"\xffbackup.TAR.GZ"));
+    assert!(ByteTypes::Archive.equals_bytes(b"tar::Archive"));
+    assert!(ByteTypes::Archive.equals_bytes(b"a tar file"));
+    assert!(!ByteTypes::Archive.equals_bytes(b"a \xfftar file"));
+    assert!(ByteTypes::Archive.equals_os_str(std::ffi::OsStr::new("backup.tar.gz")));
+
+    assert!(ByteTypes::Other("Custom".to_owned()).equals_bytes(b"CUSTOM"));
+}
+
+#[test]
+pub fn test_match_details() {
+    use enum_helper::compare_str::{MatchKind, StrMatch};
+    let uuid = OtherTypes::UUID {
+        format: UUIDFormat::Bytes,
+        version: UUIDVersion::Version1,
+    };
+    assert_eq!(
+        uuid.match_details("my_uuid_type"),
+        Some(StrMatch {
+            kind: MatchKind::Contains,
+            pattern: "uuid",
+            range: 3..7,
+        })
+    );
+    assert_eq!(
+        uuid.match_details("Uuid").map(|m| m.kind),
+        Some(MatchKind::Equals)
+    );
+    assert_eq!(
+        OtherTypes::FilePath.match_details("FilePath").map(|m| m.kind),
+        Some(MatchKind::VariantName)
+    );
+    assert_eq!(OtherTypes::FilePath.match_details("uuid"), None);
+
+    let upper = AsciiCaseTypes::UUID.match_details("my UUID").unwrap();
+    assert_eq!(upper.pattern, "UUID");
+    assert_eq!(upper.range, 3..7);
+
+    let field = ByteTypes::Other("Custom".to_owned());
+    let details = field.match_details("custom").unwrap();
+    assert_eq!(details.kind, MatchKind::Field);
+    assert_eq!(details.pattern, "Custom");
+    assert_eq!(
+        ByteTypes::Archive.match_details("backup.tar.gz").unwrap().range,
+        6..13
+    );
+}
+
+#[test]
+pub fn test_patterns() {
+    use enum_helper::compare_str::MatchKind;
+    let uuid = OtherTypes::UUID {
+        format: UUIDFormat::Bytes,
+        version: UUIDVersion::Version1,
+    };
+    assert_eq!(uuid.aliases(), &["uuid::Uuid", "Uuid", "UUID"]);
+    assert_eq!(uuid.substrings(), &["uuid"]);
+    assert_eq!(OtherTypes::FilePath.substrings(), &[] as &[&str]);
+    assert_eq!(AsciiCaseTypes::UUID.aliases(), &["Uuid", "UUID"]);
+
+    assert_eq!(OtherTypes::PATTERNS.len(), 4);
+    assert_eq!(
+        OtherTypes::PATTERNS[0],
+        (
+            "UUID",
+            &[
+                (MatchKind::Equals, "uuid::Uuid"),
+                (MatchKind::Equals, "Uuid"),
+                (MatchKind::Contains, "uuid"),
+                (MatchKind::VariantName, "UUID"),
+            ][..]
+        )
+    );
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, CompareToStr)]
+#[compare_str(from_str, bytes)]
+pub enum FallbackTypes {
+    #[compare_str(contains["uuid"], except["uuid_legacy", "legacy_uuid"])]
+    Uuid,
+    #[compare_str(equals["uuid_legacy"])]
+    Legacy,
+    #[compare_str(fallback, except["null"])]
+    Other,
+}
+
+#[test]
+pub fn test_fallback_and_except() {
+    use enum_helper::compare_str::MatchKind;
+    assert_eq!(FallbackTypes::Uuid, "my_uuid");
+    assert_ne!(FallbackTypes::Uuid, "uuid_legacy");
+    assert_ne!(FallbackTypes::Uuid, "legacy_uuid");
+    assert_eq!(FallbackTypes::match_str("uuid_legacy"), Some(FallbackTypes::Legacy));
+    assert_eq!(FallbackTypes::match_str("uuid"), Some(FallbackTypes::Uuid));
+
+    assert_eq!(FallbackTypes::Other, "anything");
+    assert_eq!(FallbackTypes::Other, "legacy_uuid");
+    assert_ne!(FallbackTypes::Other, "uuid");
+    assert_ne!(FallbackTypes::Other, "null");
+    assert_eq!(FallbackTypes::match_str("anything"), Some(FallbackTypes::Other));
+    assert_eq!(FallbackTypes::match_str("null"), None);
+    assert!("null".parse::<FallbackTypes>().is_err());
+
+    assert!(FallbackTypes::Other.equals_bytes(b"anything"));
+    assert!(!FallbackTypes::Uuid.equals_bytes(b"uuid_legacy"));
+
+    assert_eq!(FallbackTypes::Uuid.match_details("uuid_legacy"), None);
+    assert_eq!(
+        FallbackTypes::Other.match_details("anything").map(|m| m.kind),
+        Some(MatchKind::Fallback)
+    );
+}
+
+fn is_version(value: &str) -> bool {
+    value
+        .strip_prefix('v')
+        .is_some_and(|rest| !rest.is_empty() && rest.bytes().all(|b| b.is_ascii_digit()))
+}
+fn is_short_code(code: &PredicateTypes, value: &str) -> bool {
+    matches!(code, PredicateTypes::Code(len) if value.len() == *len)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, CompareToStr, EnumOfKeys)]
+#[compare_str(ignore_ascii_case, bytes)]
+#[enum_of_keys(PredicateTypesKeys, impl_common_traits)]
+pub enum PredicateTypes {
+    #[compare_str(equals["latest"], with = is_version)]
+    Version,
+    #[compare_str(with_self = is_short_code)]
+    Code(usize),
+}
+
+#[test]
+pub fn test_predicates() {
+    use enum_helper::compare_str::MatchKind;
+    assert_eq!(PredicateTypes::Version, "latest");
+    assert_eq!(PredicateTypes::Version, "V12");
+    assert_ne!(PredicateTypes::Version, "v");
+    assert!(PredicateTypes::Version.equals_bytes(b"v3"));
+    assert_eq!(PredicateTypes::match_str("V2"), Some(PredicateTypes::Version));
+    assert_eq!(
+        PredicateTypes::Version.match_details("v2").map(|m| (m.kind, m.pattern)),
+        Some((MatchKind::Predicate, "is_version"))
+    );
+
+    assert_eq!(PredicateTypes::Code(3), "abc");
+    assert_ne!(PredicateTypes::Code(3), "abcd");
+    assert!(PredicateTypes::Code(2).equals_bytes(b"ab"));
+    assert_eq!(
+        PredicateTypes::PATTERNS[1],
+        (
+            "Code",
+            &[
+                (MatchKind::VariantName, "Code"),
+                (MatchKind::Predicate, "is_short_code")
+            ][..]
+        )
+    );
+    // The key has no fields so `with_self` is not forwarded to it
+    assert!(PredicateTypesKeys::Version.equals_str("v2"));
+    assert!(PredicateTypesKeys::Code.equals_str("Code"));
+    assert!(!PredicateTypesKeys::Code.equals_str("abc"));
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, CompareToStr)]
+#[compare_str(from_str, ignore_ascii_case)]
+pub enum SimilarTypes {
+    #[compare_str(equals["PathBuf"], similar)]
+    FilePath,
+    #[compare_str(equals["Duration"], similar[threshold = 2])]
+    Time,
+    #[compare_str(equals["Instant"])]
+    Instant,
+}
+
+#[test]
+pub fn test_similar_and_suggest() {
+    use enum_helper::compare_str::MatchKind;
+    assert_eq!(SimilarTypes::FilePath, "pathbf");
+    assert_ne!(SimilarTypes::FilePath, "pthbf");
+    assert_eq!(SimilarTypes::Time, "DuRatin");
+    assert_eq!(SimilarTypes::match_str("Durtin"), Some(SimilarTypes::Time));
+    assert_eq!(SimilarTypes::match_str("Instnt"), None);
+    assert_eq!(
+        SimilarTypes::FilePath
+            .match_details("PathBf")
+            .map(|m| (m.kind, m.pattern)),
+        Some((MatchKind::Similar, "PathBuf"))
+    );
+
+    assert_eq!(SimilarTypes::suggest("Instnt"), Some("Instant"));
+    assert_eq!(SimilarTypes::suggest("filepth"), Some("FilePath"));
+    assert_eq!(SimilarTypes::suggest("nothing"), None);
+    let error = "Instnt".parse::<SimilarTypes>().unwrap_err();
+    assert_eq!(error.suggestion, Some("Instant"));
+    assert_eq!(
+        error.to_string(),
+        "`Instnt` does not match any variant. Did you mean `Instant`?"
+    );
+
+    use enum_helper::compare_str::CaseMode;
+    let long = "a".repeat(100);
+    assert_eq!(CaseMode::Sensitive.edit_distance(&long[1..], &long), 1);
+    assert_eq!(CaseMode::CaseFold.edit_distance("KITTEN", "sitting"), 3);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, CompareToStr)]
+#[compare_str(include_variant = false)]
+pub enum MimeTypes {
+    #[compare_str(equals_from = "tests/aliases/mime.txt", equals["json"])]
+    Json,
+    #[compare_str(equals["text/plain"])]
+    Text,
+}
+
+#[test]
+pub fn test_equals_from() {
+    assert_eq!(MimeTypes::Json, "application/json");
+    assert_eq!(MimeTypes::Json, "application/x-json");
+    assert_eq!(MimeTypes::Json, "json");
+    assert_ne!(MimeTypes::Json, "application/commented-out");
+    assert_eq!(MimeTypes::match_str("text/json"), Some(MimeTypes::Json));
+    assert_eq!(
+        MimeTypes::Json.aliases(),
+        &["application/json", "text/json", "application/x-json", "json"]
+    );
+}
+
+#[test]
+pub fn test_str_matcher() {
+    use enum_helper::compare_str::StrMatcher;
+    let matcher = StrMatcher::new()
+        .with_alias(AsciiCaseTypes::FilePath, "X-Customer-Path")
+        .with_alias(AsciiCaseTypes::UUID, "guid");
+    let matcher = std::sync::Arc::new(matcher);
+    let shared = matcher.clone();
+    std::thread::spawn(move || {
+        assert_eq!(shared.resolve("x-customer-path"), Some(AsciiCaseTypes::FilePath));
+    })
+    .join()
+    .unwrap();
+
+    assert!(matcher.matches(&AsciiCaseTypes::FilePath, "PATHBUF"));
+    assert!(matcher.matches(&AsciiCaseTypes::FilePath, "x-customer-PATH"));
+    assert!(!matcher.matches(&AsciiCaseTypes::UUID, "x-customer-path"));
+    assert_eq!(matcher.resolve("GUID"), Some(AsciiCaseTypes::UUID));
+    assert_eq!(matcher.resolve("uuid"), Some(AsciiCaseTypes::UUID));
+    assert_eq!(matcher.resolve("unknown"), None);
+    // A runtime alias is exact. So it wins over the `contains["UUID"]` of UUID
+    let exact = StrMatcher::new().with_alias(AsciiCaseTypes::FilePath, "uuid-path");
+    assert_eq!(exact.resolve("UUID-PATH"), Some(AsciiCaseTypes::FilePath));
+    assert_eq!(exact.resolve("my-uuid"), Some(AsciiCaseTypes::UUID));
+    assert_eq!(
+        matcher.aliases(&AsciiCaseTypes::UUID).collect::<Vec<_>>(),
+        vec!["Uuid", "UUID", "guid"]
+    );
+}
+
+const FILE_PATH: AsciiCaseTypes = match AsciiCaseTypes::match_str_const("PathBuf") {
+    Some(value) => value,
+    None => panic!("Unknown type"),
+};
+const _: () = assert!(AsciiCaseTypes::match_str_const("PATHBUF").is_some());
+const _: () = assert!(OtherTypes::FilePath.equals_str_const("Path"));
+
+#[test]
+pub fn test_const() {
+    assert_eq!(FILE_PATH, AsciiCaseTypes::FilePath);
+    assert_eq!(
+        AsciiCaseTypes::match_str_const("uuid"),
+        Some(AsciiCaseTypes::UUID)
+    );
+    assert_eq!(OtherTypes::match_str_const("uuid"), None);
+    assert!(ByteTypes::Get.equals_str_const("x-GET-all"));
+    assert!(ByteTypes::Archive.equals_str_const("backup.tar.gz"));
+    assert!(!ByteTypes::Archive.equals_str_const("backup.zip"));
+}
+
+#[cfg(feature = "syn")]
+#[test]
+pub fn test_syn() {
+    let path: syn::Path = syn::parse_quote!(::std::path::PathBuf);
+    assert!(OtherTypes::FilePath.equals_path(&path));
+    let ty: syn::Type = syn::parse_quote!(uuid::Uuid);
+    let uuid = OtherTypes::UUID {
+        format: UUIDFormat::Bytes,
+        version: UUIDVersion::Version1,
+    };
+    assert!(uuid.equals_type(&ty));
+    let ty: syn::Type = syn::parse_quote!(my::Uuid<T>);
+    assert!(uuid.equals_type(&ty));
+    let ty: syn::Type = syn::parse_quote!(Option<PathBuf>);
+    assert!(!OtherTypes::FilePath.equals_type(&ty));
+    let ty: syn::Type = syn::parse_quote!(&PathBuf);
+    assert!(!OtherTypes::FilePath.equals_type(&ty));
+    // `contains["uuid"]` is not checked against paths
+    let path: syn::Path = syn::parse_quote!(uuid::Error);
+    assert!(!uuid.equals_path(&path));
+    assert!(uuid.equals_str("uuid::Error"));
+}
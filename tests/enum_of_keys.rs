@@ -52,3 +52,202 @@ pub fn test() {
         println!("{:?}", option.unwrap());
     }
 }
+
+#[derive(EnumOfKeys, Debug)]
+#[enum_of_keys(TestEnumAttributesKeys, impl_common_traits)]
+pub enum TestEnumAttributes {
+    /// Always available
+    A,
+    #[cfg(any())]
+    Disabled(String),
+    #[cfg_attr(any(), allow(dead_code))]
+    B(u32),
+    #[deprecated(note = "use A")]
+    Old,
+}
+
+#[test]
+#[allow(deprecated)]
+pub fn test_propagated_attributes() {
+    use enum_helper::HasKeyEnum;
+    assert_eq!(TestEnumAttributes::A.get_key(), TestEnumAttributesKeys::A);
+    assert_eq!(TestEnumAttributes::B(1).get_key(), TestEnumAttributesKeys::B);
+    assert_eq!(TestEnumAttributes::Old, TestEnumAttributesKeys::Old);
+}
+
+#[derive(EnumOfKeys)]
+#[enum_of_keys(TestEnumDescriptionKeys, default_in_cow)]
+pub enum TestEnumDescription {
+    /// The Size Extension
+    ///
+    /// Second line
+    Size(u64),
+    #[enum_of_keys(description = "Upgrade to TLS")]
+    /// Ignored doc comment
+    StartTLS,
+    Auth(Vec<String>),
+    /// Any other extension
+    #[enum_of_keys(default)]
+    Other(String),
+}
+
+#[test]
+pub fn test_description() {
+    use enum_helper::{HasKeyEnum, KeyEnum};
+    assert_eq!(
+        TestEnumDescription::Size(1).get_key().description(),
+        Some("The Size Extension\n\nSecond line")
+    );
+    assert_eq!(
+        TestEnumDescriptionKeys::StartTLS.description(),
+        Some("Upgrade to TLS")
+    );
+    assert_eq!(TestEnumDescriptionKeys::Auth.description(), None);
+    assert_eq!(
+        TestEnumDescriptionKeys::Other("X".into()).description(),
+        Some("Any other extension")
+    );
+    assert_eq!(
+        TestEnumDescriptionKeys::DESCRIPTIONS,
+        &[
+            ("Size", Some("The Size Extension\n\nSecond line")),
+            ("StartTLS", Some("Upgrade to TLS")),
+            ("Auth", None),
+            ("Other", Some("Any other extension")),
+        ]
+    );
+}
+
+#[derive(EnumOfKeys)]
+#[enum_of_keys(TestEnumMetaKeys, default_in_cow)]
+pub enum TestEnumMeta {
+    #[enum_of_keys(meta(status = 404, severity = "warn"))]
+    NotFound,
+    #[enum_of_keys(meta(status = 500, retry = true, code = 7u8))]
+    Internal(String),
+    Unknown,
+    #[enum_of_keys(default, meta(severity = "error"))]
+    Other(String),
+}
+
+#[test]
+pub fn test_meta() {
+    const STATUS: Option<i64> = TestEnumMetaKeys::NotFound.status();
+    assert_eq!(STATUS, Some(404));
+    assert_eq!(TestEnumMetaKeys::NotFound.severity(), Some("warn"));
+    assert_eq!(TestEnumMetaKeys::NotFound.retry(), None);
+    assert_eq!(TestEnumMetaKeys::Internal.status(), Some(500));
+    assert_eq!(TestEnumMetaKeys::Internal.retry(), Some(true));
+    assert_eq!(TestEnumMetaKeys::Internal.code(), Some(7u8));
+    assert_eq!(TestEnumMetaKeys::Unknown.status(), None);
+    assert_eq!(
+        TestEnumMetaKeys::Other("X".into()).severity(),
+        Some("error")
+    );
+}
+
+#[derive(EnumOfKeys, Debug, PartialEq)]
+#[enum_of_keys(TestEnumConversionKeys, impl_common_traits)]
+pub enum TestEnumConversion {
+    A(String),
+    B,
+    #[enum_of_keys(from_default)]
+    C {
+        a: String,
+        b: u32,
+    },
+    #[enum_of_keys(default = a, from_default)]
+    Default {
+        a: String,
+        b: u32,
+    },
+}
+
+#[test]
+pub fn test_conversions() {
+    fn key_of(value: impl Into<TestEnumConversionKeys>) -> TestEnumConversionKeys {
+        value.into()
+    }
+    assert_eq!(key_of(&TestEnumConversion::B), TestEnumConversionKeys::B);
+    assert_eq!(
+        key_of(TestEnumConversion::A("a".into())),
+        TestEnumConversionKeys::A
+    );
+
+    assert_eq!(
+        TestEnumConversion::try_from(TestEnumConversionKeys::B),
+        Ok(TestEnumConversion::B)
+    );
+    assert_eq!(
+        TestEnumConversion::try_from(TestEnumConversionKeys::C),
+        Ok(TestEnumConversion::C {
+            a: String::new(),
+            b: 0
+        })
+    );
+    assert_eq!(
+        TestEnumConversion::try_from(TestEnumConversionKeys::Default("X".into())),
+        Ok(TestEnumConversion::Default {
+            a: "X".into(),
+            b: 0
+        })
+    );
+    let error = TestEnumConversion::try_from(TestEnumConversionKeys::A).unwrap_err();
+    assert_eq!(error.into_key(), TestEnumConversionKeys::A);
+
+    let cow_value = TestEnumCow::Default("X".into());
+    let key: TestEnumCowKeys<'_> = (&cow_value).into();
+    assert!(matches!(
+        TestEnumCow::try_from(key),
+        Ok(TestEnumCow::Default(value)) if value == "X"
+    ));
+}
+
+#[derive(EnumOfKeys, Debug, PartialEq)]
+#[enum_of_keys(TestEnumMarkerKeys, markers)]
+pub enum TestEnumMarker {
+    Size(u64),
+    StartTLS,
+    Auth { mechanisms: Vec<String>, initial: bool },
+    #[enum_of_keys(default)]
+    Other(String),
+}
+
+#[test]
+pub fn test_markers() {
+    use enum_helper::VariantMarker;
+    let mut values = vec![
+        TestEnumMarker::StartTLS,
+        TestEnumMarker::Size(10),
+        TestEnumMarker::Auth {
+            mechanisms: vec!["PLAIN".into()],
+            initial: true,
+        },
+    ];
+    assert_eq!(
+        values.get_payload::<test_enum_marker_keys::Size>(),
+        Some(&10)
+    );
+    assert_eq!(
+        values.get_payload::<test_enum_marker_keys::StartTLS>(),
+        Some(())
+    );
+    assert_eq!(values.get_payload::<test_enum_marker_keys::Other>(), None);
+    let (mechanisms, initial) = values
+        .get_payload::<test_enum_marker_keys::Auth>()
+        .unwrap();
+    assert_eq!(mechanisms, &["PLAIN"]);
+    assert!(*initial);
+
+    if let Some(size) = test_enum_marker_keys::Size::extract_mut(&mut values[1]) {
+        *size = 20;
+    }
+    assert_eq!(
+        test_enum_marker_keys::Size::into_payload(values.remove(1)),
+        Ok(20)
+    );
+    assert_eq!(
+        test_enum_marker_keys::Size::into_payload(TestEnumMarker::StartTLS),
+        Err(TestEnumMarker::StartTLS)
+    );
+}
@@ -0,0 +1,11 @@
+use enum_helper::CompareToStr;
+
+#[derive(CompareToStr)]
+pub enum Duplicate {
+    #[compare_str(equals["json"])]
+    Json,
+    #[compare_str(equals["json"])]
+    AlsoJson,
+}
+
+fn main() {}
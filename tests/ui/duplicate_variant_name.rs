@@ -0,0 +1,10 @@
+use enum_helper::CompareToStr;
+
+#[derive(CompareToStr)]
+pub enum Duplicate {
+    #[compare_str(equals["Text"])]
+    Json,
+    Text,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use enum_helper::CompareToStr;
+
+#[derive(CompareToStr)]
+pub enum Mime {
+    // The manifest directory exists but is not a file
+    #[compare_str(equals_from = ".")]
+    Json,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use enum_helper::CompareToStr;
+
+#[derive(CompareToStr)]
+pub enum Mime {
+    #[compare_str(equals_from = "tests/ui/missing.txt")]
+    Json,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#![deny(deprecated)]
+use enum_helper::CompareToStr;
+
+#[derive(CompareToStr)]
+pub enum Overlap {
+    #[compare_str(contains["uuid"])]
+    Loose,
+    #[compare_str(equals["uuidv7"])]
+    Exact,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+#![deny(deprecated)]
+use enum_helper::{CompareToStr, EnumOfKeys};
+
+#[derive(CompareToStr, EnumOfKeys)]
+#[compare_str(allow_overlap, include_variant = false)]
+#[enum_of_keys(OptionsKeys)]
+pub enum Options {
+    #[compare_str(equals["B"])]
+    A,
+    B,
+    #[compare_str(contains["uuid"])]
+    Loose(String),
+    #[compare_str(equals["uuidv7"])]
+    Exact,
+}
+
+fn main() {}
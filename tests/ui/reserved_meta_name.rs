@@ -0,0 +1,12 @@
+use enum_helper::EnumOfKeys;
+
+#[derive(EnumOfKeys)]
+#[enum_of_keys(StatusKeys)]
+pub enum Status {
+    /// Everything is fine
+    #[enum_of_keys(meta(description = "ok"))]
+    Ok,
+    Failed(String),
+}
+
+fn main() {}